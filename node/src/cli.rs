@@ -1,5 +1,30 @@
 use sc_cli::RunCmd;
 
+/// How blocks are authored when Aura and GRANDPA are swapped out for manual seal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sealing {
+	/// Author a block as soon as a transaction enters the pool.
+	Instant,
+	/// Author blocks only when requested over the `engine_createBlock` RPC.
+	Manual,
+	/// Author a block every given number of milliseconds.
+	Interval(u64),
+}
+
+impl std::str::FromStr for Sealing {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"instant" => Self::Instant,
+			"manual" => Self::Manual,
+			millis => Self::Interval(millis.parse().map_err(|_| {
+				format!("expected `instant`, `manual` or a block time in milliseconds, got `{millis}`")
+			})?),
+		})
+	}
+}
+
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
 	#[command(subcommand)]
@@ -7,6 +32,15 @@ pub struct Cli {
 
 	#[clap(flatten)]
 	pub run: RunCmd,
+
+	/// Author blocks with manual seal instead of Aura and GRANDPA.
+	///
+	/// `instant` authors a block for every transaction entering the pool, `manual` only authors
+	/// blocks when asked to over RPC and a number authors a block every that many milliseconds.
+	/// In every mode blocks can be created and finalized on demand with the `engine_createBlock`
+	/// and `engine_finalizeBlock` RPC methods.
+	#[arg(long, value_name = "instant|manual|MILLIS")]
+	pub sealing: Option<Sealing>,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -1,5 +1,7 @@
 //! Substrate Node Template CLI library.
 #![warn(missing_docs)]
+// `sc_cli::Error` is large, and every subcommand returns it.
+#![allow(clippy::result_large_err)]
 
 mod benchmarking;
 mod chain_spec;
@@ -0,0 +1,57 @@
+//! RPC methods for the balances of `pallet_assets` tokens.
+
+use std::sync::Arc;
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
+use pallet_template_runtime_api::AssetsApi as AssetsRuntimeApi;
+use solochain_template_runtime::{opaque::Block, AccountId, AssetId, Balance};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(server)]
+pub trait AssetsApi<BlockHash> {
+	/// The balance of `who` in every asset it holds, at block `at` or the best block.
+	#[method(name = "assets_accountBalances")]
+	fn account_balances(
+		&self,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AssetId, Balance)>>;
+}
+
+/// Serves [`AssetsApiServer`] from the runtime's `AssetsApi`.
+pub struct Assets<C> {
+	client: Arc<C>,
+}
+
+impl<C> Assets<C> {
+	/// Create a new instance answering from `client`.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+impl<C> AssetsApiServer<<Block as BlockT>::Hash> for Assets<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AssetsRuntimeApi<Block, AccountId, AssetId, Balance>,
+{
+	fn account_balances(
+		&self,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AssetId, Balance)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().account_balances(at, who).map_err(|error| {
+			ErrorObjectOwned::owned(
+				RUNTIME_ERROR,
+				"Unable to query asset balances.",
+				Some(error.to_string()),
+			)
+		})
+	}
+}
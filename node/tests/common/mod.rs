@@ -0,0 +1,229 @@
+//! Helpers for end-to-end tests that run the node binary on localhost.
+//!
+//! Every [`TestNode`] starts `solochain-template-node --dev --sealing manual` with its own
+//! temporary base path and free ports, so tests can run in parallel and offline. Blocks are only
+//! authored when a test asks for one with [`TestNode::create_block`].
+
+#![allow(dead_code)]
+
+use codec::{Decode, Encode};
+use frame_system::AccountInfo;
+use jsonrpsee::{
+	core::client::ClientT,
+	http_client::{HttpClient, HttpClientBuilder},
+	rpc_params,
+};
+use pallet_template::Profile;
+use solochain_template_runtime::{
+	AccountId, AssetId, Balance, Hash, NftCollectionId, NftId, Nonce, Runtime, RuntimeCall,
+	RuntimeEvent, SignedExtra, SignedPayload, UncheckedExtrinsic, VERSION,
+};
+use sp_core::{crypto::Ss58Codec, twox_128, Bytes, Pair};
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::generic::Era;
+use std::{
+	net::TcpListener,
+	process::{Child, Command, Stdio},
+	time::Duration,
+};
+
+/// How long to wait for a freshly spawned node to answer RPC calls. Unoptimized builds spend a
+/// few minutes compiling the runtime before the RPC server comes up.
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// A development node running as a child process with manual sealing.
+pub struct TestNode {
+	child: Child,
+	rpc: HttpClient,
+	_base_path: tempfile::TempDir,
+}
+
+impl TestNode {
+	/// Start a node on the development chain and wait until its RPC server is up.
+	pub async fn start() -> Self {
+		let base_path = tempfile::tempdir().expect("temporary directories can be created");
+		let rpc_port = free_port();
+
+		let child = Command::new(env!("CARGO_BIN_EXE_solochain-template-node"))
+			.args(["--dev", "--sealing", "manual", "--no-prometheus", "--no-mdns", "--no-telemetry"])
+			.args(["--listen-addr", "/ip4/127.0.0.1/tcp/0"])
+			.arg("--base-path")
+			.arg(base_path.path())
+			.args(["--rpc-port", &rpc_port.to_string()])
+			.stdout(Stdio::null())
+			.stderr(Stdio::null())
+			.spawn()
+			.expect("the node binary is built by cargo for integration tests");
+
+		let rpc = HttpClientBuilder::default()
+			.build(format!("http://127.0.0.1:{rpc_port}"))
+			.expect("the RPC url is valid");
+		let mut node = Self { child, rpc, _base_path: base_path };
+		node.wait_for_rpc().await;
+		node
+	}
+
+	async fn wait_for_rpc(&mut self) {
+		let started = std::time::Instant::now();
+		loop {
+			if let Ok(Some(status)) = self.child.try_wait() {
+				panic!("node exited before its RPC server came up: {status}");
+			}
+			if self.rpc.request::<serde_json::Value, _>("system_health", rpc_params![]).await.is_ok()
+			{
+				return;
+			}
+			assert!(started.elapsed() < STARTUP_TIMEOUT, "node RPC server did not come up in time");
+			tokio::time::sleep(Duration::from_millis(250)).await;
+		}
+	}
+
+	/// Sign `call` with `signer`'s next nonce and submit it to the transaction pool.
+	pub async fn submit(&self, signer: Sr25519Keyring, call: RuntimeCall) -> Hash {
+		self.submit_paying_in(signer, call, None).await
+	}
+
+	/// Like [`Self::submit`], but pay the fee in asset `asset_id` rather than the native currency
+	/// if it is `Some`.
+	pub async fn submit_paying_in(
+		&self,
+		signer: Sr25519Keyring,
+		call: RuntimeCall,
+		asset_id: Option<AssetId>,
+	) -> Hash {
+		let genesis_hash: Hash = self
+			.rpc
+			.request("chain_getBlockHash", rpc_params![0])
+			.await
+			.expect("genesis hash is available");
+		let nonce: Nonce = self
+			.rpc
+			.request("system_accountNextIndex", rpc_params![signer.to_account_id().to_ss58check()])
+			.await
+			.expect("account nonce is available");
+
+		let extra: SignedExtra = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(Era::Immortal),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_template::FeelessPosting::from(
+				pallet_asset_tx_payment::ChargeAssetTxPayment::<Runtime>::from(0, asset_id),
+			),
+			frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
+		);
+		let payload = SignedPayload::from_raw(
+			call.clone(),
+			extra.clone(),
+			(
+				(),
+				VERSION.spec_version,
+				VERSION.transaction_version,
+				genesis_hash,
+				genesis_hash,
+				(),
+				(),
+				(),
+				None,
+			),
+		);
+		let signature = payload.using_encoded(|payload| signer.pair().sign(payload));
+		let extrinsic = UncheckedExtrinsic::new_signed(
+			call,
+			signer.to_account_id().into(),
+			signature.into(),
+			extra,
+		);
+
+		self.rpc
+			.request("author_submitExtrinsic", rpc_params![Bytes(extrinsic.encode())])
+			.await
+			.expect("extrinsic is accepted by the pool")
+	}
+
+	/// Author and finalize a block with everything that is in the pool.
+	pub async fn create_block(&self) -> Hash {
+		let block: serde_json::Value = self
+			.rpc
+			.request("engine_createBlock", rpc_params![true, true, Option::<Hash>::None])
+			.await
+			.expect("manual seal creates blocks on demand");
+		serde_json::from_value(block["hash"].clone()).expect("created blocks have a hash")
+	}
+
+	/// Read and decode the value stored under `key` at block `at`.
+	pub async fn storage<T: Decode>(&self, key: Vec<u8>, at: Hash) -> Option<T> {
+		let value: Option<Bytes> = self
+			.rpc
+			.request("state_getStorage", rpc_params![Bytes(key), at])
+			.await
+			.expect("storage can be queried");
+		value.map(|value| T::decode(&mut &value[..]).expect("storage value decodes"))
+	}
+
+	/// The free balance of `who` at block `at`.
+	pub async fn free_balance(&self, who: Sr25519Keyring, at: Hash) -> Balance {
+		self.storage::<AccountInfo<Nonce, <Runtime as frame_system::Config>::AccountData>>(
+			frame_system::Account::<Runtime>::hashed_key_for(who.to_account_id()),
+			at,
+		)
+		.await
+		.map(|account| account.data.free)
+		.unwrap_or_default()
+	}
+
+	/// The balances of `who` in every asset it holds at block `at`, through the
+	/// `assets_accountBalances` RPC method.
+	pub async fn asset_balances(&self, who: Sr25519Keyring, at: Hash) -> Vec<(AssetId, Balance)> {
+		self.rpc
+			.request("assets_accountBalances", rpc_params![who.to_account_id().to_ss58check(), at])
+			.await
+			.expect("asset balances can be queried")
+	}
+
+	/// The profile of the user registered as `name` at block `at`, through the `SocialApi`
+	/// runtime API.
+	pub async fn profile(
+		&self,
+		name: &str,
+		at: Hash,
+	) -> Option<Profile<AccountId, NftCollectionId, NftId>> {
+		let result: Bytes = self
+			.rpc
+			.request("state_call", rpc_params!["SocialApi_profile", Bytes(name.encode()), at])
+			.await
+			.expect("the runtime API can be called");
+		Decode::decode(&mut &result[..]).expect("profiles decode")
+	}
+
+	/// The events deposited in block `at`.
+	pub async fn events(&self, at: Hash) -> Vec<RuntimeEvent> {
+		self.storage::<Vec<frame_system::EventRecord<RuntimeEvent, Hash>>>(
+			[twox_128(b"System"), twox_128(b"Events")].concat(),
+			at,
+		)
+		.await
+		.unwrap_or_default()
+		.into_iter()
+		.map(|record| record.event)
+		.collect()
+	}
+}
+
+impl Drop for TestNode {
+	fn drop(&mut self) {
+		let _ = self.child.kill();
+		let _ = self.child.wait();
+	}
+}
+
+/// Ask the OS for a port that nothing is listening on right now.
+fn free_port() -> u16 {
+	TcpListener::bind("127.0.0.1:0")
+		.and_then(|listener| listener.local_addr())
+		.expect("a local port can be bound")
+		.port()
+}
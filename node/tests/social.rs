@@ -0,0 +1,314 @@
+//! End-to-end tests for the template pallet against a running development node.
+//!
+//! The node binary is spawned on localhost with manual sealing, see [`common::TestNode`].
+
+mod common;
+
+use common::TestNode;
+use frame_support::BoundedVec;
+use pallet_template::{Event, Tweet};
+use solochain_template_runtime::{
+	configs::ProxyType, AssetId, BalancesCall, BlockNumber, Runtime, RuntimeCall, RuntimeEvent,
+};
+use sp_keyring::Sr25519Keyring;
+
+fn bounded<const N: u32>(s: &str) -> BoundedVec<u8, frame_support::traits::ConstU32<N>> {
+	s.as_bytes().to_vec().try_into().unwrap()
+}
+
+fn create_user(name: &str, password: &str) -> RuntimeCall {
+	RuntimeCall::TemplateModule(pallet_template::Call::create_user {
+		name: bounded(name),
+		password: bounded(password),
+		year: 1990,
+		month: 5,
+		day: 4,
+	})
+}
+
+fn create_tweet(name: &str, password: &str, content: &str) -> RuntimeCall {
+	RuntimeCall::TemplateModule(pallet_template::Call::create_tweet {
+		name: bounded(name),
+		password: bounded(password),
+		content: bounded(content),
+	})
+}
+
+#[tokio::test]
+async fn create_user_and_tweet_through_rpc() {
+	let node = TestNode::start().await;
+	let alice = Sr25519Keyring::Alice;
+
+	node.submit(alice, create_user("alice", "secret")).await;
+	let block = node.create_block().await;
+
+	let events = node.events(block).await;
+	assert!(events.contains(&RuntimeEvent::TemplateModule(Event::NameStored {
+		name: bounded("alice"),
+		who: alice.to_account_id(),
+	})));
+	let owner = node
+		.storage(pallet_template::AccountByName::<Runtime>::hashed_key_for(bounded("alice")), block)
+		.await;
+	assert_eq!(owner, Some(alice.to_account_id()));
+
+	// Registered users tweet without paying fees.
+	let balance_before = node.free_balance(alice, block).await;
+	node.submit(alice, create_tweet("alice", "secret", "Hello from an e2e test!")).await;
+	let block = node.create_block().await;
+	assert_eq!(node.free_balance(alice, block).await, balance_before);
+
+	let events = node.events(block).await;
+	assert!(events.iter().any(|event| matches!(
+		event,
+		RuntimeEvent::TemplateModule(Event::TweetCreated { tweet_id: 0, .. })
+	)));
+	let tweet: Option<Tweet<BlockNumber>> = node
+		.storage(pallet_template::Tweets::<Runtime>::hashed_key_for(bounded("alice"), 0), block)
+		.await;
+	assert_eq!(tweet.map(|tweet| tweet.content), Some(bounded("Hello from an e2e test!")));
+}
+
+#[tokio::test]
+async fn tweeting_with_a_wrong_password_fails() {
+	let node = TestNode::start().await;
+	let bob = Sr25519Keyring::Bob;
+
+	node.submit(bob, create_user("bob", "secret")).await;
+	node.create_block().await;
+
+	node.submit(bob, create_tweet("bob", "not-the-password", "Let me in")).await;
+	let block = node.create_block().await;
+
+	let events = node.events(block).await;
+	assert!(events.iter().any(|event| matches!(
+		event,
+		RuntimeEvent::System(frame_system::Event::ExtrinsicFailed { .. })
+	)));
+	let tweets: Option<Tweet<BlockNumber>> = node
+		.storage(pallet_template::Tweets::<Runtime>::hashed_key_for(bounded("bob"), 0), block)
+		.await;
+	assert!(tweets.is_none());
+}
+
+#[tokio::test]
+async fn tip_in_a_community_token_and_pay_fees_with_it() {
+	let node = TestNode::start().await;
+	let (alice, bob) = (Sr25519Keyring::Alice, Sr25519Keyring::Bob);
+	const TOKEN: AssetId = 7;
+
+	// Only sufficient assets, which root creates, can pay for fees.
+	let force_create = RuntimeCall::Assets(pallet_assets::Call::force_create {
+		id: TOKEN.into(),
+		owner: alice.to_account_id().into(),
+		is_sufficient: true,
+		min_balance: 1,
+	});
+	node.submit(alice, RuntimeCall::Sudo(pallet_sudo::Call::sudo { call: Box::new(force_create) }))
+		.await;
+	node.create_block().await;
+	node.submit(
+		alice,
+		RuntimeCall::Assets(pallet_assets::Call::mint {
+			id: TOKEN.into(),
+			beneficiary: bob.to_account_id().into(),
+			amount: 1_000_000,
+		}),
+	)
+	.await;
+	node.submit(alice, create_user("alice", "secret")).await;
+	node.create_block().await;
+	node.submit(alice, create_tweet("alice", "secret", "Tips welcome")).await;
+	let block = node.create_block().await;
+	assert_eq!(node.asset_balances(bob, block).await, vec![(TOKEN, 1_000_000)]);
+
+	let native_before = node.free_balance(bob, block).await;
+	let tip = RuntimeCall::TemplateModule(pallet_template::Call::tip_tweet_with_asset {
+		name: bounded("alice"),
+		tweet_id: 0,
+		asset_id: TOKEN,
+		amount: 1_000,
+	});
+	node.submit_paying_in(bob, tip, Some(TOKEN)).await;
+	let block = node.create_block().await;
+
+	let events = node.events(block).await;
+	assert!(events.contains(&RuntimeEvent::TemplateModule(Event::TippedWithAsset {
+		who: bob.to_account_id(),
+		name: bounded("alice"),
+		tweet_id: 0,
+		asset_id: TOKEN,
+		amount: 1_000,
+	})));
+	let fee = events
+		.iter()
+		.find_map(|event| match event {
+			RuntimeEvent::AssetTxPayment(pallet_asset_tx_payment::Event::AssetTxFeePaid {
+				actual_fee,
+				asset_id: Some(TOKEN),
+				..
+			}) => Some(*actual_fee),
+			_ => None,
+		})
+		.expect("the fee is paid in the token");
+	assert_eq!(node.free_balance(bob, block).await, native_before);
+	assert_eq!(node.asset_balances(alice, block).await, vec![(TOKEN, 1_000)]);
+	assert_eq!(node.asset_balances(bob, block).await, vec![(TOKEN, 1_000_000 - 1_000 - fee)]);
+}
+
+#[tokio::test]
+async fn avatar_nft_is_dropped_from_the_profile_once_transferred() {
+	let node = TestNode::start().await;
+	let (alice, bob) = (Sr25519Keyring::Alice, Sr25519Keyring::Bob);
+
+	node.submit(
+		alice,
+		RuntimeCall::Nfts(pallet_nfts::Call::create {
+			admin: alice.to_account_id().into(),
+			config: Default::default(),
+		}),
+	)
+	.await;
+	node.submit(alice, create_user("alice", "secret")).await;
+	node.create_block().await;
+	node.submit(
+		alice,
+		RuntimeCall::Nfts(pallet_nfts::Call::mint {
+			collection: 0,
+			item: 42,
+			mint_to: alice.to_account_id().into(),
+			witness_data: None,
+		}),
+	)
+	.await;
+	node.create_block().await;
+
+	node.submit(
+		alice,
+		RuntimeCall::TemplateModule(pallet_template::Call::set_avatar {
+			name: bounded("alice"),
+			password: bounded("secret"),
+			collection: 0,
+			item: 42,
+		}),
+	)
+	.await;
+	let block = node.create_block().await;
+	assert!(node.events(block).await.contains(&RuntimeEvent::TemplateModule(Event::AvatarSet {
+		name: bounded("alice"),
+		collection: 0,
+		item: 42,
+	})));
+	assert_eq!(node.profile("alice", block).await.and_then(|profile| profile.avatar), Some((0, 42)));
+
+	node.submit(
+		alice,
+		RuntimeCall::Nfts(pallet_nfts::Call::transfer {
+			collection: 0,
+			item: 42,
+			dest: bob.to_account_id().into(),
+		}),
+	)
+	.await;
+	// The block that transfers the NFT also removes the avatar in `on_idle`.
+	let block = node.create_block().await;
+	let profile = node.profile("alice", block).await.expect("alice is registered");
+	assert_eq!(profile.avatar, None);
+	assert!(node
+		.events(block)
+		.await
+		.contains(&RuntimeEvent::TemplateModule(Event::AvatarCleared { name: bounded("alice") })));
+	let avatar: Option<(u32, u32)> = node
+		.storage(pallet_template::Avatars::<Runtime>::hashed_key_for(alice.to_account_id()), block)
+		.await;
+	assert_eq!(avatar, None);
+}
+
+#[tokio::test]
+async fn social_proxy_posts_for_a_brand_but_cannot_spend_its_funds() {
+	let node = TestNode::start().await;
+	let (brand, manager) = (Sr25519Keyring::Alice, Sr25519Keyring::Bob);
+
+	node.submit(brand, create_user("brand", "secret")).await;
+	node.submit(
+		brand,
+		RuntimeCall::Proxy(pallet_proxy::Call::add_proxy {
+			delegate: manager.to_account_id().into(),
+			proxy_type: ProxyType::Social,
+			delay: 0,
+		}),
+	)
+	.await;
+	node.create_block().await;
+
+	let proxy = |call| {
+		RuntimeCall::Proxy(pallet_proxy::Call::proxy {
+			real: brand.to_account_id().into(),
+			force_proxy_type: None,
+			call: Box::new(call),
+		})
+	};
+	let posts = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+		calls: vec![
+			create_tweet("brand", "secret", "Launch day!"),
+			create_tweet("brand", "secret", "Thanks for coming"),
+		],
+	});
+	node.submit(manager, proxy(posts)).await;
+
+	// Neither transfers, tips nor expiring tweets, which hold a deposit, get through, whether
+	// proxied directly or inside a batch.
+	let transfer = RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+		dest: manager.to_account_id().into(),
+		value: 1,
+	});
+	let tip = RuntimeCall::TemplateModule(pallet_template::Call::tip_tweet {
+		name: bounded("brand"),
+		tweet_id: 0,
+		amount: 1,
+	});
+	let asset_tip = RuntimeCall::TemplateModule(pallet_template::Call::tip_tweet_with_asset {
+		name: bounded("brand"),
+		tweet_id: 0,
+		asset_id: 1,
+		amount: 1,
+	});
+	let expiring = RuntimeCall::TemplateModule(pallet_template::Call::create_expiring_tweet {
+		name: bounded("brand"),
+		password: bounded("secret"),
+		content: bounded("Gone soon"),
+		expires_at: 100,
+	});
+	let spends = [transfer, tip.clone(), asset_tip.clone(), expiring].into_iter().chain(
+		[tip, asset_tip].map(|call| {
+			RuntimeCall::Utility(pallet_utility::Call::batch_all {
+				calls: vec![create_tweet("brand", "secret", "Tip us"), call],
+			})
+		}),
+	);
+	for call in spends {
+		node.submit(manager, proxy(call)).await;
+	}
+	let block = node.create_block().await;
+
+	for (tweet_id, content) in [(0, "Launch day!"), (1, "Thanks for coming")] {
+		let tweet: Option<Tweet<BlockNumber>> = node
+			.storage(
+				pallet_template::Tweets::<Runtime>::hashed_key_for(bounded("brand"), tweet_id),
+				block,
+			)
+			.await;
+		assert_eq!(tweet.map(|tweet| tweet.content), Some(bounded(content)));
+	}
+	let results: Vec<_> = node
+		.events(block)
+		.await
+		.into_iter()
+		.filter_map(|event| match event {
+			RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted { result }) => Some(result),
+			_ => None,
+		})
+		.collect();
+	let filtered = Err(frame_system::Error::<Runtime>::CallFiltered.into());
+	assert_eq!(results, [vec![Ok(())], vec![filtered; 6]].concat());
+}
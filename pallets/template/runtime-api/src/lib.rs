@@ -0,0 +1,77 @@
+//! Runtime API for clients of the template pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+pub use pallet_template::{Judgement, Message, Poll, Profile, Tweet};
+
+sp_api::decl_runtime_apis! {
+	/// Social data kept by the template pallet that clients need to render timelines.
+	pub trait SocialApi<AccountId, Balance, BlockNumber, NftCollectionId, NftId>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		NftCollectionId: Codec,
+		NftId: Codec,
+	{
+		/// The profile of the user registered as `name`, with the judgements of the current
+		/// registrars and the avatar only if they still own its NFT.
+		fn profile(name: Vec<u8>) -> Option<Profile<AccountId, NftCollectionId, NftId>>;
+
+		/// Names muted by the user registered as `name`, empty if `name` is not registered.
+		fn muted_names(name: Vec<u8>) -> Vec<Vec<u8>>;
+
+		/// Up to `limit` messages between the users registered as `name` and `with` with their
+		/// IDs, oldest first, starting at message ID `start`. Pass the last returned ID plus one
+		/// as `start` to get the next page.
+		fn messages(
+			name: Vec<u8>,
+			with: Vec<u8>,
+			start: u32,
+			limit: u32,
+		) -> Vec<(u32, Message<AccountId, Balance, BlockNumber>)>;
+
+		/// The poll on tweet `tweet_id` of the user registered as `author`, with its current
+		/// tallies.
+		fn poll(author: Vec<u8>, tweet_id: u32) -> Option<Poll<BlockNumber>>;
+
+		/// Total amount tipped to tweet `tweet_id` of the user registered as `author`.
+		fn tips(author: Vec<u8>, tweet_id: u32) -> Balance;
+
+		/// The tweets the user registered as `name` pinned to their profile, with their IDs.
+		fn pinned_tweets(name: Vec<u8>) -> Vec<(u32, Tweet<BlockNumber>)>;
+
+		/// The tweets `who` bookmarked, with their IDs, oldest bookmark first. Deleted and
+		/// hidden tweets are left out.
+		fn bookmarks(who: AccountId) -> Vec<(u32, Tweet<BlockNumber>)>;
+
+		/// Up to `limit` of the newest tweets of the members of list `list` of the user
+		/// registered as `owner`, with their IDs. Private lists come back empty unless `viewer`
+		/// is their owner.
+		fn list_tweets(
+			owner: Vec<u8>,
+			list: Vec<u8>,
+			viewer: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(u32, Tweet<BlockNumber>)>;
+	}
+
+	/// Balances of the assets users tip tweets with.
+	pub trait AssetsApi<AccountId, AssetId, Balance>
+	where
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// The balance of `who` in every asset it holds.
+		fn account_balances(who: AccountId) -> Vec<(AssetId, Balance)>;
+
+		/// Total amounts tipped to tweet `tweet_id` of the user registered as `author`, per
+		/// asset.
+		fn asset_tips(author: Vec<u8>, tweet_id: u32) -> Vec<(AssetId, Balance)>;
+	}
+}
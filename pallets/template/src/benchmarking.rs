@@ -4,31 +4,1281 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Template;
+use alloc::{vec, vec::Vec};
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{
+	traits::{fungible, fungibles, ConstU32, EnsureOrigin, Get, Hooks},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::{traits::Zero, Saturating};
 
-#[benchmarks]
+/// Upper bounds of the `BoundedVec` arguments taken by the pallet's calls.
+const MAX_NAME_LEN: u32 = 256;
+const MAX_PASSWORD_LEN: u32 = 256;
+const MAX_CONTENT_LEN: u32 = 280;
+const MAX_MESSAGE_LEN: u32 = 1024;
+
+/// A `len` bytes long argument made of `byte`.
+fn bytes<const N: u32>(byte: u8, len: u32) -> BoundedVec<u8, ConstU32<N>> {
+	vec![byte; len as usize].try_into().expect("benchmark components stay within the bound")
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Register `caller` under a `n` bytes long name with a `p` bytes long password.
+fn register<T: Config>(
+	caller: &T::AccountId,
+	n: u32,
+	p: u32,
+) -> (BoundedVec<u8, ConstU32<256>>, BoundedVec<u8, ConstU32<256>>) {
+	let name = bytes(b'a', n);
+	let password = bytes(b'p', p);
+	Template::<T>::create_user(
+		RawOrigin::Signed(caller.clone()).into(),
+		name.clone(),
+		password.clone(),
+		1990,
+		5,
+		4,
+	)
+	.expect("benchmark users can be registered");
+	(name, password)
+}
+
+/// Register another account under a `n` bytes long name, for calls that target a user.
+fn other_user<T: Config>(n: u32) -> (BoundedVec<u8, ConstU32<256>>, T::AccountId) {
+	let other: T::AccountId = account("other", 0, 0);
+	let name = bytes(b'o', n);
+	Template::<T>::create_user(
+		RawOrigin::Signed(other.clone()).into(),
+		name.clone(),
+		bytes(b'p', MAX_PASSWORD_LEN),
+		1990,
+		5,
+		4,
+	)
+	.expect("benchmark users can be registered");
+	(name, other)
+}
+
+/// Register another account under a `n` bytes long name, post a tweet as it and file `r`
+/// reports against that tweet.
+fn reported_tweet<T: Config>(n: u32, r: u32) -> (BoundedVec<u8, ConstU32<256>>, T::AccountId) {
+	let (name, author) = other_user::<T>(n);
+	post_tweets::<T>(&author, &name, &bytes(b'p', MAX_PASSWORD_LEN), 1);
+	for i in 0..r {
+		Template::<T>::report_tweet(
+			RawOrigin::Signed(account("reporter", i, 0)).into(),
+			name.clone(),
+			0,
+			bytes(b'r', MAX_NAME_LEN),
+		)
+		.expect("benchmark tweets can be reported");
+	}
+	(name, author)
+}
+
+/// Fill the registrar list with `count` registrars and return them.
+fn registrars<T: Config>(count: u32) -> Vec<T::AccountId> {
+	let registrars: Vec<T::AccountId> =
+		(0..count).map(|i| account("registrar", i, 0)).collect();
+	Registrars::<T>::put(
+		BoundedVec::try_from(registrars.clone()).expect("at most `MaxRegistrars` registrars"),
+	);
+	registrars
+}
+
+/// Give `who` enough to hold a few message deposits.
+fn fund<T: Config>(who: &T::AccountId) {
+	let amount = <T::Currency as fungible::Inspect<_>>::minimum_balance()
+		.saturating_add(T::MessageDeposit::get().saturating_mul(2u32.into()));
+	<T::Currency as fungible::Mutate<_>>::set_balance(who, amount);
+}
+
+/// Give `who` enough to hold `count` expiring tweet deposits.
+fn fund_expiring<T: Config>(who: &T::AccountId, count: u32) {
+	let amount = <T::Currency as fungible::Inspect<_>>::minimum_balance()
+		.saturating_add(T::ExpiringTweetDeposit::get().saturating_mul(count.into()));
+	<T::Currency as fungible::Mutate<_>>::set_balance(who, amount);
+}
+
+/// Hold an expiring tweet deposit from `author` for each of its first `t` tweets, funding it
+/// first.
+fn expiry_deposits<T: Config>(author: &T::AccountId, t: u32) {
+	fund_expiring::<T>(author, t);
+	let deposit = T::ExpiringTweetDeposit::get();
+	for tweet_id in 0..t {
+		<T::Currency as fungible::MutateHold<_>>::hold(
+			&HoldReason::ExpiringTweetDeposit.into(),
+			author,
+			deposit,
+		)
+		.expect("the author was funded");
+		ExpiryDeposits::<T>::insert(author, tweet_id, deposit);
+	}
+}
+
+/// Hold a message deposit from `sender` on top of what it holds already, minting it first.
+fn message_deposit<T: Config>(sender: &T::AccountId) {
+	let deposit = T::MessageDeposit::get();
+	<T::Currency as fungible::Mutate<_>>::mint_into(sender, deposit).expect("deposits can be minted");
+	<T::Currency as fungible::MutateHold<_>>::hold(&HoldReason::MessageDeposit.into(), sender, deposit)
+		.expect("the sender was funded");
+}
+
+/// Register `caller` and another account under `n` bytes long names, and send a message of
+/// `c` bytes from `caller` to the other account.
+fn conversation<T: Config>(
+	caller: &T::AccountId,
+	n: u32,
+	c: u32,
+) -> (BoundedVec<u8, ConstU32<256>>, T::AccountId) {
+	register::<T>(caller, n, MAX_PASSWORD_LEN);
+	fund::<T>(caller);
+	let (name, other) = other_user::<T>(n);
+	Template::<T>::register_messaging_key(RawOrigin::Signed(other.clone()).into(), [1; 32])
+		.expect("benchmark users can register a messaging key");
+	if c > 0 {
+		Template::<T>::send_message(
+			RawOrigin::Signed(caller.clone()).into(),
+			name.clone(),
+			bytes(b'm', c),
+			[2; 24],
+			[3; 32],
+		)
+		.expect("benchmark users can send messages");
+	}
+	(name, other)
+}
+
+/// Poll options of the maximum number and length.
+fn poll_options() -> BoundedVec<BoundedVec<u8, ConstU32<64>>, ConstU32<4>> {
+	BoundedVec::truncate_from((0..4).map(|i| bytes(b'0' + i, 64)).collect())
+}
+
+/// A poll open until `ends_at` with the maximum number of options.
+fn open_poll<T: Config>(index: u32, ends_at: BlockNumberFor<T>) -> Poll<BlockNumberFor<T>> {
+	Poll {
+		index,
+		options: poll_options(),
+		tallies: BoundedVec::truncate_from(vec![0; 4]),
+		ends_at,
+		closed: false,
+	}
+}
+
+/// Schedule `count` tweets of the maximum length as `name`, due from ten blocks on.
+fn schedule_tweets<T: Config>(
+	caller: &T::AccountId,
+	name: &BoundedVec<u8, ConstU32<256>>,
+	password: &BoundedVec<u8, ConstU32<256>>,
+	count: u32,
+) {
+	let now = frame_system::Pallet::<T>::block_number();
+	for i in 0..count {
+		// Spread over as few blocks as `MaxScheduledTweetsPerBlock` allows
+		let at = now.saturating_add((10 + i / T::MaxScheduledTweetsPerBlock::get()).into());
+		Template::<T>::schedule_tweet(
+			RawOrigin::Signed(caller.clone()).into(),
+			name.clone(),
+			password.clone(),
+			bytes(b's', MAX_CONTENT_LEN),
+			at,
+		)
+		.expect("benchmark users can schedule tweets");
+	}
+}
+
+/// Give `name` the maximum number of lists, each with the maximum number of members.
+fn full_lists<T: Config>(name: &BoundedVec<u8, ConstU32<256>>) {
+	let members: Vec<T::AccountId> =
+		(0..T::MaxListMembers::get()).map(|i| account("member", i, 0)).collect();
+	for i in 0..T::MaxListsPerUser::get() {
+		let user_list =
+			UserList::<T> { members: BoundedVec::truncate_from(members.clone()), private: false };
+		Lists::<T>::insert(name, list_name(i), user_list);
+	}
+	ListCount::<T>::insert(name, T::MaxListsPerUser::get());
+}
+
+/// Give `who` `count` devices with every scope.
+fn devices<T: Config>(who: &T::AccountId, count: u32) {
+	for i in 0..count {
+		let scopes = vec![DeviceScope::Post, DeviceScope::Delete, DeviceScope::Profile];
+		let device = Device { scopes: BoundedVec::truncate_from(scopes), expires_at: None };
+		Devices::<T>::insert(who, account::<T::AccountId>("device", i, 0), device);
+	}
+	DeviceCount::<T>::insert(who, count);
+}
+
+/// Give `name` a recovery config with `count` friends, all of whom have to vouch.
+fn recovery_config<T: Config>(
+	name: &BoundedVec<u8, ConstU32<256>>,
+	count: u32,
+) -> Vec<T::AccountId> {
+	let friends: Vec<T::AccountId> = (0..count).map(|i| account("friend", i, 0)).collect();
+	let config = RecoveryConfig::<T> {
+		friends: BoundedVec::truncate_from(friends.clone()),
+		threshold: count,
+		delay: Zero::zero(),
+	};
+	Recoveries::<T>::insert(name, config);
+	friends
+}
+
+/// Have `rescuer` initiate the recovery of `name`, which has a recovery config.
+fn active_recovery<T: Config>(name: &BoundedVec<u8, ConstU32<256>>, rescuer: &T::AccountId) {
+	let amount = <T::Currency as fungible::Inspect<_>>::minimum_balance()
+		.saturating_add(T::RecoveryDeposit::get());
+	<T::Currency as fungible::Mutate<_>>::set_balance(rescuer, amount);
+	Template::<T>::initiate_recovery(RawOrigin::Signed(rescuer.clone()).into(), name.clone())
+		.expect("benchmark names can be recovered");
+}
+
+/// Give each of the `t` tweets of `author` a tip total and a poll that is still open, so they
+/// have as much to move along with their name as possible.
+fn tipped_polls<T: Config>(author: &T::AccountId, t: u32) {
+	let now = frame_system::Pallet::<T>::block_number();
+	for tweet_id in 0..t {
+		let ends_at = now.saturating_add((tweet_id + 1).into());
+		let tip = <T::Currency as fungible::Inspect<_>>::minimum_balance();
+		Tips::<T>::insert(author, tweet_id, tip);
+		Polls::<T>::insert(author, tweet_id, open_poll::<T>(tweet_id, ends_at));
+		PollsEndingAt::<T>::mutate(ends_at, |ending| {
+			ending.try_push((author.clone(), tweet_id)).expect("one poll ends per block")
+		});
+	}
+}
+
+/// A name of the maximum length, distinct per `i`.
+fn friend_name(i: u32) -> BoundedVec<u8, ConstU32<256>> {
+	let mut name = bytes::<256>(b'f', 252);
+	name.try_extend(i.to_le_bytes().into_iter()).expect("252 + 4 bytes fit");
+	name
+}
+
+/// A list name of the maximum length, distinct per `i`.
+fn list_name(i: u32) -> BoundedVec<u8, ConstU32<64>> {
+	let mut name = bytes::<64>(b'l', 60);
+	name.try_extend(i.to_le_bytes().into_iter()).expect("60 + 4 bytes fit");
+	name
+}
+
+/// Post `t` tweets of the maximum length as `name`.
+fn post_tweets<T: Config>(
+	caller: &T::AccountId,
+	name: &BoundedVec<u8, ConstU32<256>>,
+	password: &BoundedVec<u8, ConstU32<256>>,
+	t: u32,
+) {
+	for _ in 0..t {
+		Template::<T>::create_tweet(
+			RawOrigin::Signed(caller.clone()).into(),
+			name.clone(),
+			password.clone(),
+			bytes(b't', MAX_CONTENT_LEN),
+		)
+		.expect("benchmark users can tweet");
+	}
+}
+
+#[benchmarks(where T::Assets: fungibles::Create<T::AccountId>, AssetIdOf<T>: From<u32>)]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn do_something() {
-		let value = 100u32;
+	fn create_user(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let name = bytes(b'a', n);
+		let password = bytes(b'p', p);
+
+		#[extrinsic_call]
+		create_user(RawOrigin::Signed(caller.clone()), name.clone(), password, 1990, 5, 4);
+
+		assert_eq!(AccountByName::<T>::get(&name), Some(caller));
+	}
+
+	#[benchmark]
+	fn verify_user(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+
+		#[extrinsic_call]
+		verify_user(RawOrigin::Signed(caller.clone()), name.clone(), password);
+
+		assert_last_event::<T>(Event::LoginSuccessful { name, who: caller }.into());
+	}
+
+	#[benchmark]
+	fn create_tweet(
+		n: Linear<1, MAX_NAME_LEN>,
+		p: Linear<1, MAX_PASSWORD_LEN>,
+		c: Linear<1, MAX_CONTENT_LEN>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+
+		#[extrinsic_call]
+		create_tweet(RawOrigin::Signed(caller), name.clone(), password, bytes(b't', c));
+
+		assert!(Tweets::<T>::contains_key(&name, 0));
+	}
+
+	#[benchmark]
+	fn get_user_birthday(n: Linear<1, MAX_NAME_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, _) = register::<T>(&caller, n, MAX_PASSWORD_LEN);
+
+		#[extrinsic_call]
+		get_user_birthday(RawOrigin::Signed(caller), name);
+	}
+
+	#[benchmark]
+	fn get_user_tweets(n: Linear<1, MAX_NAME_LEN>, t: Linear<0, { T::MaxTweetsPerUser::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, MAX_PASSWORD_LEN);
+		post_tweets::<T>(&caller, &name, &password, t);
+
+		#[extrinsic_call]
+		get_user_tweets(RawOrigin::Signed(caller), name);
+	}
+
+	#[benchmark]
+	fn update_user_name(
+		n: Linear<1, MAX_NAME_LEN>,
+		p: Linear<1, MAX_PASSWORD_LEN>,
+		t: Linear<0, { T::MaxTweetsPerUser::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		post_tweets::<T>(&caller, &name, &password, t);
+		full_lists::<T>(&name);
+		recovery_config::<T>(&name, T::MaxFriends::get());
+		let new_name = bytes(b'b', n);
+
+		#[extrinsic_call]
+		update_user_name(RawOrigin::Signed(caller.clone()), name, password, new_name.clone());
+
+		assert_eq!(AccountByName::<T>::get(&new_name), Some(caller));
+		assert_eq!(TweetCount::<T>::get(&new_name), t);
+		assert_eq!(ListCount::<T>::get(&new_name), T::MaxListsPerUser::get());
+	}
+
+	#[benchmark]
+	fn update_password(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let new_password = bytes(b'q', p);
+
+		#[extrinsic_call]
+		update_password(RawOrigin::Signed(caller), name.clone(), password, new_password);
+
+		assert_last_event::<T>(Event::PasswordHashed { name }.into());
+	}
+
+	#[benchmark]
+	fn delete_tweet(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		post_tweets::<T>(&caller, &name, &password, 1);
+		expiry_deposits::<T>(&caller, 1);
+
+		#[extrinsic_call]
+		delete_tweet(RawOrigin::Signed(caller), name.clone(), password, 0);
+
+		assert!(!Tweets::<T>::contains_key(&name, 0));
+	}
+
+	#[benchmark]
+	fn delete_user(
+		n: Linear<1, MAX_NAME_LEN>,
+		p: Linear<1, MAX_PASSWORD_LEN>,
+		t: Linear<0, { T::MaxTweetsPerUser::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		post_tweets::<T>(&caller, &name, &password, t);
+		expiry_deposits::<T>(&caller, t);
+		message_deposit::<T>(&caller);
+		full_lists::<T>(&name);
+		devices::<T>(&caller, T::MaxDevices::get());
+		schedule_tweets::<T>(&caller, &name, &password, T::MaxScheduledTweets::get());
+		recovery_config::<T>(&name, T::MaxFriends::get());
+
+		#[extrinsic_call]
+		delete_user(RawOrigin::Signed(caller), name.clone(), password);
+
+		assert!(!AccountByName::<T>::contains_key(&name));
+	}
+
+	#[benchmark]
+	fn block_user(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let (target, blocked) = other_user::<T>(n);
+
+		#[extrinsic_call]
+		block_user(RawOrigin::Signed(caller.clone()), name, password, target);
+
+		assert!(Blocked::<T>::contains_key(&caller, &blocked));
+	}
+
+	#[benchmark]
+	fn unblock_user(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let (target, blocked) = other_user::<T>(n);
+		Blocked::<T>::insert(&caller, &blocked, ());
+
+		#[extrinsic_call]
+		unblock_user(RawOrigin::Signed(caller.clone()), name, password, target);
+
+		assert!(!Blocked::<T>::contains_key(&caller, &blocked));
+	}
+
+	#[benchmark]
+	fn mute_user(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let (target, muted) = other_user::<T>(n);
+
+		#[extrinsic_call]
+		mute_user(RawOrigin::Signed(caller.clone()), name, password, target);
+
+		assert!(Muted::<T>::contains_key(&caller, &muted));
+	}
+
+	#[benchmark]
+	fn unmute_user(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let (target, muted) = other_user::<T>(n);
+		Muted::<T>::insert(&caller, &muted, ());
+
+		#[extrinsic_call]
+		unmute_user(RawOrigin::Signed(caller.clone()), name, password, target);
+
+		assert!(!Muted::<T>::contains_key(&caller, &muted));
+	}
+
+	#[benchmark]
+	fn report_tweet(
+		n: Linear<1, MAX_NAME_LEN>,
+		r: Linear<0, { T::MaxReportsPerTweet::get() - 1 }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, author) = reported_tweet::<T>(n, r);
+
+		#[extrinsic_call]
+		report_tweet(RawOrigin::Signed(caller), name, 0, bytes(b'r', MAX_NAME_LEN));
+
+		assert_eq!(Reports::<T>::get(&author, 0).map(|reports| reports.len() as u32), Some(r + 1));
+	}
+
+	#[benchmark]
+	fn dismiss_reports(n: Linear<1, MAX_NAME_LEN>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ModeratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (name, author) = reported_tweet::<T>(n, T::MaxReportsPerTweet::get());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name, 0);
+
+		assert!(!Reports::<T>::contains_key(&author, 0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn hide_tweet(n: Linear<1, MAX_NAME_LEN>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ModeratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (name, author) = reported_tweet::<T>(n, T::MaxReportsPerTweet::get());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name, 0);
+
+		assert!(HiddenTweets::<T>::contains_key(&author, 0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_tweet(n: Linear<1, MAX_NAME_LEN>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ModeratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (name, _) = reported_tweet::<T>(n, T::MaxReportsPerTweet::get());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name.clone(), 0);
+
+		assert!(!Tweets::<T>::contains_key(&name, 0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn suspend_name(n: Linear<1, MAX_NAME_LEN>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ModeratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (name, account) = other_user::<T>(n);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name);
+
+		assert!(Suspended::<T>::contains_key(&account));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn appeal(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		Suspended::<T>::insert(&caller, Suspension::Indefinite);
+
+		#[extrinsic_call]
+		appeal(
+			RawOrigin::Signed(caller.clone()),
+			name,
+			password,
+			AppealSubject::Suspension,
+			bytes(b's', MAX_NAME_LEN),
+		);
+
+		assert!(Appeals::<T>::contains_key(&caller, AppealSubject::Suspension));
+	}
+
+	#[benchmark]
+	fn resolve_appeal(n: Linear<1, MAX_NAME_LEN>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ModeratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (name, account) = other_user::<T>(n);
+		Suspended::<T>::insert(&account, Suspension::Indefinite);
+		Appeals::<T>::insert(&account, AppealSubject::Suspension, bytes(b's', MAX_NAME_LEN));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name, AppealSubject::Suspension, true);
+
+		assert!(!Suspended::<T>::contains_key(&account));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn suspend_user(n: Linear<1, MAX_NAME_LEN>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (name, account) = other_user::<T>(n);
+		let until = frame_system::Pallet::<T>::block_number() + 100u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name, until);
+
+		assert_eq!(Suspended::<T>::get(&account), Some(Suspension::Until(until)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unsuspend_user(n: Linear<1, MAX_NAME_LEN>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (name, account) = other_user::<T>(n);
+		Suspended::<T>::insert(&account, Suspension::Indefinite);
+		Appeals::<T>::insert(&account, AppealSubject::Suspension, bytes(b's', MAX_NAME_LEN));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name);
+
+		assert!(!Suspended::<T>::contains_key(&account));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn ban_user(n: Linear<1, MAX_NAME_LEN>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (name, account) = other_user::<T>(n);
+		Suspended::<T>::insert(&account, Suspension::Indefinite);
+		Appeals::<T>::insert(&account, AppealSubject::Suspension, bytes(b's', MAX_NAME_LEN));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name);
+
+		assert_eq!(Suspended::<T>::get(&account), Some(Suspension::Banned));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_registrar() {
+		registrars::<T>(T::MaxRegistrars::get() - 1);
+		let registrar: T::AccountId = account("new registrar", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, registrar.clone());
+
+		assert!(Registrars::<T>::get().contains(&registrar));
+	}
+
+	#[benchmark]
+	fn remove_registrar() {
+		let registrar = registrars::<T>(T::MaxRegistrars::get()).pop().expect("one registrar");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, registrar.clone());
+
+		assert!(!Registrars::<T>::get().contains(&registrar));
+	}
+
+	#[benchmark]
+	fn provide_judgement(n: Linear<1, MAX_NAME_LEN>) {
+		let mut registrars = registrars::<T>(T::MaxRegistrars::get());
+		let (name, _) = other_user::<T>(n);
+		let registrar = registrars.pop().expect("one registrar");
+		let others = registrars.into_iter().map(|other| (other, Judgement::Reasonable)).collect::<Vec<_>>();
+		Judgements::<T>::insert(&name, BoundedVec::try_from(others).expect("fewer than the maximum"));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(registrar), name.clone(), Judgement::KnownGood);
+
+		assert_eq!(Judgements::<T>::get(&name).len() as u32, T::MaxRegistrars::get());
+	}
+
+	#[benchmark]
+	fn revoke_judgement(n: Linear<1, MAX_NAME_LEN>) {
+		let registrars = registrars::<T>(T::MaxRegistrars::get());
+		let (name, _) = other_user::<T>(n);
+		let registrar = registrars.last().cloned().expect("one registrar");
+		let judgements = registrars.into_iter().map(|by| (by, Judgement::Reasonable)).collect::<Vec<_>>();
+		Judgements::<T>::insert(&name, BoundedVec::try_from(judgements).expect("the maximum"));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(registrar), name.clone());
+
+		assert_eq!(Judgements::<T>::get(&name).len() as u32, T::MaxRegistrars::get() - 1);
+	}
+
+	#[benchmark]
+	fn register_messaging_key() {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller, MAX_NAME_LEN, MAX_PASSWORD_LEN);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), [1; 32]);
+
+		assert_eq!(MessagingKeys::<T>::get(&caller), Some([1; 32]));
+	}
+
+	#[benchmark]
+	fn send_message(n: Linear<1, MAX_NAME_LEN>, c: Linear<1, MAX_MESSAGE_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, other) = conversation::<T>(&caller, n, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, bytes(b'm', c), [2; 24], [3; 32]);
+
+		assert!(Messages::<T>::contains_key((caller.clone().min(other.clone()), caller.max(other)), 0));
+	}
+
+	#[benchmark]
+	fn delete_message() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (_, other) = conversation::<T>(&caller, MAX_NAME_LEN, MAX_MESSAGE_LEN);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), other.clone(), 0);
+
+		assert!(!Messages::<T>::contains_key((caller.clone().min(other.clone()), caller.max(other)), 0));
+	}
+
+	#[benchmark]
+	fn create_poll_tweet(
+		n: Linear<1, MAX_NAME_LEN>,
+		p: Linear<1, MAX_PASSWORD_LEN>,
+		c: Linear<1, MAX_CONTENT_LEN>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let ends_at = frame_system::Pallet::<T>::block_number().saturating_add(T::MaxPollDuration::get());
+		let others = (1..T::MaxPollsEndingPerBlock::get()).map(|i| (account("author", i, 0), 0));
+		PollsEndingAt::<T>::insert(ends_at, BoundedVec::truncate_from(others.collect::<Vec<_>>()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, password, bytes(b't', c), poll_options(), ends_at);
+
+		assert!(Polls::<T>::contains_key(&caller, 0));
+	}
+
+	#[benchmark]
+	fn vote_poll(n: Linear<1, MAX_NAME_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller, MAX_NAME_LEN, MAX_PASSWORD_LEN);
+		let (name, author) = other_user::<T>(n);
+		let ends_at = frame_system::Pallet::<T>::block_number().saturating_add(T::MaxPollDuration::get());
+		Polls::<T>::insert(&author, 0, open_poll::<T>(0, ends_at));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, 0, 3);
+
+		assert_eq!(PollVotes::<T>::get(0, VoterIds::<T>::get(&caller).unwrap()), Some(3));
+	}
+
+	#[benchmark]
+	fn close_polls(p: Linear<0, { T::MaxPollsEndingPerBlock::get() }>) {
+		let (_, author) = other_user::<T>(MAX_NAME_LEN);
+		let now = frame_system::Pallet::<T>::block_number();
+		for tweet_id in 0..p {
+			Polls::<T>::insert(&author, tweet_id, open_poll::<T>(tweet_id, now));
+		}
+		let ending = (0..p).map(|tweet_id| (author.clone(), tweet_id)).collect::<Vec<_>>();
+		PollsEndingAt::<T>::insert(now, BoundedVec::truncate_from(ending));
+
+		#[block]
+		{
+			Template::<T>::on_initialize(now);
+		}
+
+		assert!((0..p).all(|tweet_id| Polls::<T>::get(&author, tweet_id).is_some_and(|poll| poll.closed)));
+	}
+
+	#[benchmark]
+	fn schedule_tweet(
+		n: Linear<1, MAX_NAME_LEN>,
+		p: Linear<1, MAX_PASSWORD_LEN>,
+		c: Linear<1, MAX_CONTENT_LEN>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let count = T::MaxScheduledTweets::get() - 1;
+		schedule_tweets::<T>(&caller, &name, &password, count);
+		// The block the last of those went to, if it has room for another
+		let at = frame_system::Pallet::<T>::block_number()
+			.saturating_add((10 + count / T::MaxScheduledTweetsPerBlock::get()).into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, password, bytes(b't', c), at);
+
+		assert_eq!(ScheduledTweets::<T>::get(&caller).len() as u32, T::MaxScheduledTweets::get());
+	}
+
+	#[benchmark]
+	fn cancel_scheduled_tweet(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let count = T::MaxScheduledTweets::get();
+		schedule_tweets::<T>(&caller, &name, &password, count);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, password, count - 1);
+
+		assert_eq!(ScheduledTweets::<T>::get(&caller).len() as u32, count - 1);
+	}
+
+	#[benchmark]
+	fn publish_scheduled_tweet() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, MAX_NAME_LEN, MAX_PASSWORD_LEN);
+		let count = T::MaxScheduledTweets::get();
+		schedule_tweets::<T>(&caller, &name, &password, count);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), count - 1);
+
+		assert!(Tweets::<T>::contains_key(&name, 0));
+	}
+
+	#[benchmark]
+	fn create_expiring_tweet(
+		n: Linear<1, MAX_NAME_LEN>,
+		p: Linear<1, MAX_PASSWORD_LEN>,
+		c: Linear<1, MAX_CONTENT_LEN>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		fund_expiring::<T>(&caller, 1);
+		let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		let others = (1..T::MaxExpiringPerBlock::get()).map(|i| (account("author", i, 0), 0));
+		ExpiringAt::<T>::insert(expires_at, BoundedVec::truncate_from(others.collect::<Vec<_>>()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), password, bytes(b't', c), expires_at);
+
+		assert_eq!(Tweets::<T>::get(&name, 0).and_then(|tweet| tweet.expires_at), Some(expires_at));
+	}
+
+	#[benchmark]
+	fn expire_tweets(t: Linear<0, { T::MaxExpiringPerBlock::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, MAX_NAME_LEN, MAX_PASSWORD_LEN);
+		fund_expiring::<T>(&caller, t);
+		let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		for _ in 0..t {
+			Template::<T>::create_expiring_tweet(
+				RawOrigin::Signed(caller.clone()).into(),
+				name.clone(),
+				password.clone(),
+				bytes(b't', MAX_CONTENT_LEN),
+				expires_at,
+			)
+			.expect("benchmark users can post expiring tweets");
+		}
+		ExpiryCursor::<T>::put(expires_at);
+
+		#[block]
+		{
+			Template::<T>::on_idle(expires_at, Weight::MAX);
+		}
+
+		assert!(ExpiringAt::<T>::get(expires_at).is_empty());
+		assert!((0..t).all(|tweet_id| !Tweets::<T>::contains_key(&name, tweet_id)));
+	}
+
+	#[benchmark]
+	fn pin_tweet(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let pins = T::MaxPinnedTweets::get();
+		post_tweets::<T>(&caller, &name, &password, pins);
+		PinnedTweets::<T>::insert(&caller, BoundedVec::truncate_from((1..pins).collect::<Vec<_>>()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, password, 0);
+
+		assert!(PinnedTweets::<T>::get(&caller).contains(&0));
+	}
+
+	#[benchmark]
+	fn unpin_tweet(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let pins = T::MaxPinnedTweets::get();
+		PinnedTweets::<T>::insert(&caller, BoundedVec::truncate_from((0..pins).collect::<Vec<_>>()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, password, pins - 1);
+
+		assert!(!PinnedTweets::<T>::get(&caller).contains(&(pins - 1)));
+	}
+
+	#[benchmark]
+	fn bookmark(n: Linear<1, MAX_NAME_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller, MAX_NAME_LEN, MAX_PASSWORD_LEN);
+		let (name, author) = other_user::<T>(n);
+		post_tweets::<T>(&author, &name, &bytes(b'p', MAX_PASSWORD_LEN), 1);
+		let others = (1..T::MaxBookmarks::get()).map(|i| (author.clone(), i));
+		Bookmarks::<T>::insert(&caller, BoundedVec::truncate_from(others.collect::<Vec<_>>()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, 0);
+
+		assert!(BookmarkedBy::<T>::contains_key((author, 0), &caller));
+	}
+
+	#[benchmark]
+	fn unbookmark(n: Linear<1, MAX_NAME_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, author) = other_user::<T>(n);
+		let bookmarks = (0..T::MaxBookmarks::get()).map(|i| (author.clone(), i));
+		Bookmarks::<T>::insert(&caller, BoundedVec::truncate_from(bookmarks.collect::<Vec<_>>()));
+		let last = T::MaxBookmarks::get() - 1;
+		BookmarkedBy::<T>::insert((author.clone(), last), &caller, ());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, last);
+
+		assert!(!BookmarkedBy::<T>::contains_key((author, last), &caller));
+	}
+
+	#[benchmark]
+	fn remove_stale_bookmarks(b: Linear<0, 100>) {
+		let author: T::AccountId = account("author", 0, 0);
+		let tweet = (author.clone(), 0);
+		for i in 0..b {
+			let bookmarker: T::AccountId = account("bookmarker", i, 0);
+			let bookmarks = (0..T::MaxBookmarks::get()).map(|id| (author.clone(), id));
+			let bookmarks = BoundedVec::truncate_from(bookmarks.collect::<Vec<_>>());
+			Bookmarks::<T>::insert(&bookmarker, bookmarks);
+			BookmarkedBy::<T>::insert(&tweet, &bookmarker, ());
+		}
+		StaleBookmarks::<T>::insert(&tweet, ());
+		let now = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			Template::<T>::on_idle(now, Weight::MAX);
+		}
+
+		assert!(!StaleBookmarks::<T>::contains_key(&tweet));
+		assert!((0..b).all(|i| {
+			!Bookmarks::<T>::get(account::<T::AccountId>("bookmarker", i, 0)).contains(&tweet)
+		}));
+	}
+
+	#[benchmark]
+	fn create_list(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		ListCount::<T>::insert(&name, T::MaxListsPerUser::get() - 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), password, list_name(0), true);
+
+		assert!(Lists::<T>::contains_key(&name, list_name(0)));
+	}
+
+	#[benchmark]
+	fn add_to_list(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let (member, other) = other_user::<T>(n);
+		let others = (1..T::MaxListMembers::get()).map(|i| account("member", i, 0));
+		let user_list =
+			UserList::<T> { members: BoundedVec::truncate_from(others.collect()), private: false };
+		Lists::<T>::insert(&name, list_name(0), user_list);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), password, list_name(0), member);
+
+		let user_list = Lists::<T>::get(&name, list_name(0));
+		assert!(user_list.is_some_and(|list| list.members.contains(&other)));
+	}
+
+	#[benchmark]
+	fn remove_from_list(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let (member, other) = other_user::<T>(n);
+		let mut members: Vec<T::AccountId> =
+			(1..T::MaxListMembers::get()).map(|i| account("member", i, 0)).collect();
+		members.push(other.clone());
+		let user_list =
+			UserList::<T> { members: BoundedVec::truncate_from(members), private: false };
+		Lists::<T>::insert(&name, list_name(0), user_list);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), password, list_name(0), member);
+
+		let user_list = Lists::<T>::get(&name, list_name(0));
+		assert!(user_list.is_some_and(|list| !list.members.contains(&other)));
+	}
+
+	#[benchmark]
+	fn delete_list(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		full_lists::<T>(&name);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), password, list_name(0));
+
+		assert!(!Lists::<T>::contains_key(&name, list_name(0)));
+	}
+
+	#[benchmark]
+	fn tip_tweet(n: Linear<1, MAX_NAME_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, author) = other_user::<T>(n);
+		post_tweets::<T>(&author, &name, &bytes(b'p', MAX_PASSWORD_LEN), 1);
+		// The tip creates the author's account, the most expensive case
+		let amount = <T::Currency as fungible::Inspect<_>>::minimum_balance();
+		let balance = amount.saturating_mul(10u32.into());
+		<T::Currency as fungible::Mutate<_>>::set_balance(&caller, balance);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name, 0, amount);
+
+		assert_eq!(Tips::<T>::get(&author, 0), amount);
+	}
+
+		#[benchmark]
+	fn tip_tweet_with_asset(n: Linear<1, MAX_NAME_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, author) = other_user::<T>(n);
+		post_tweets::<T>(&author, &name, &bytes(b'p', MAX_PASSWORD_LEN), 1);
+		// The tweet was tipped in as many other assets as it can take but one, and the tip
+		// creates the author's account of the asset
+		let asset_id: AssetIdOf<T> = 0u32.into();
+		let amount: AssetBalanceOf<T> = 1u32.into();
+		<T::Assets as fungibles::Create<_>>::create(asset_id.clone(), caller.clone(), true, amount)
+			.expect("benchmark assets can be created");
+		<T::Assets as fungibles::Mutate<_>>::set_balance(asset_id.clone(), &caller, 10u32.into());
+		let others = (1..T::MaxTipAssets::get()).map(|i| (i.into(), amount));
+		AssetTips::<T>::insert(&author, 0, BoundedVec::truncate_from(others.collect::<Vec<_>>()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name, 0, asset_id.clone(), amount);
+
+		assert!(AssetTips::<T>::get(&author, 0).contains(&(asset_id, amount)));
+	}
+
+	#[benchmark]
+	fn set_avatar(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
 		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let (collection, item) = T::BenchmarkHelper::mint_nft(&caller);
+
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		_(RawOrigin::Signed(caller.clone()), name, password, collection, item);
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert_eq!(Avatars::<T>::get(&caller), Some((collection, item)));
 	}
 
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(100u32);
+	fn clear_avatar(n: Linear<1, MAX_NAME_LEN>) {
+		// Someone else clears the avatar after its NFT changed hands, which takes checking the
+		// owner
 		let caller: T::AccountId = whitelisted_caller();
+		let (name, author) = other_user::<T>(n);
+		Avatars::<T>::insert(&author, T::BenchmarkHelper::mint_nft(&caller));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name);
+
+		assert!(!Avatars::<T>::contains_key(&author));
+	}
+
+	#[benchmark]
+	fn remove_stale_avatars(a: Linear<0, { T::MaxAvatarChecksPerBlock::get() }>) {
+		// Every avatar checked shows an NFT its user gave away and gets cleared
+		let caller: T::AccountId = whitelisted_caller();
+		let nft = T::BenchmarkHelper::mint_nft(&caller);
+		for i in 0..a {
+			let user: T::AccountId = account("user", i, 0);
+			Names::<T>::insert(&user, bytes(b'u', MAX_NAME_LEN));
+			Avatars::<T>::insert(&user, nft);
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			Template::<T>::on_idle(now, Weight::MAX);
+		}
+
+		assert_eq!(Avatars::<T>::iter().count(), 0);
+	}
+
+	#[benchmark]
+	fn add_device(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		devices::<T>(&caller, T::MaxDevices::get() - 1);
+		let device: T::AccountId = account("new device", 0, 0);
+		let scopes = BoundedVec::truncate_from(vec![DeviceScope::Post]);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, password, device.clone(), scopes, None);
+
+		assert!(Devices::<T>::contains_key(&caller, &device));
+	}
+
+	#[benchmark]
+	fn remove_device(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		devices::<T>(&caller, T::MaxDevices::get());
+		let device: T::AccountId = account("device", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, password, device.clone());
+
+		assert!(!Devices::<T>::contains_key(&caller, &device));
+	}
+
+	#[benchmark]
+	fn set_recovery(
+		n: Linear<1, MAX_NAME_LEN>,
+		p: Linear<1, MAX_PASSWORD_LEN>,
+		f: Linear<1, { T::MaxFriends::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let friends: Vec<_> = (0..f)
+			.map(|i| {
+				let friend = friend_name(i);
+				Template::<T>::create_user(
+					RawOrigin::Signed(account("friend", i, 0)).into(),
+					friend.clone(),
+					bytes(b'p', MAX_PASSWORD_LEN),
+					1990,
+					5,
+					4,
+				)
+				.expect("benchmark users can be registered");
+				friend
+			})
+			.collect();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			name.clone(),
+			password,
+			BoundedVec::truncate_from(friends),
+			f,
+			10u32.into(),
+		);
+
+		assert!(Recoveries::<T>::contains_key(&name));
+	}
+
+	#[benchmark]
+	fn remove_recovery(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		recovery_config::<T>(&name, T::MaxFriends::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), password);
+
+		assert!(!Recoveries::<T>::contains_key(&name));
+	}
+
+	#[benchmark]
+	fn initiate_recovery(n: Linear<1, MAX_NAME_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, _) = other_user::<T>(n);
+		recovery_config::<T>(&name, T::MaxFriends::get());
+		let amount = <T::Currency as fungible::Inspect<_>>::minimum_balance()
+			.saturating_add(T::RecoveryDeposit::get());
+		<T::Currency as fungible::Mutate<_>>::set_balance(&caller, amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name.clone());
+
+		assert!(ActiveRecoveries::<T>::contains_key(&name, &caller));
+	}
+
+	#[benchmark]
+	fn vouch(n: Linear<1, MAX_NAME_LEN>) {
+		let (name, _) = other_user::<T>(n);
+		let mut friends = recovery_config::<T>(&name, T::MaxFriends::get());
+		let rescuer: T::AccountId = account("rescuer", 0, 0);
+		active_recovery::<T>(&name, &rescuer);
+		let caller = friends.pop().expect("recovery configs have a friend");
+		Names::<T>::insert(&caller, bytes(b'f', MAX_NAME_LEN));
+		ActiveRecoveries::<T>::mutate(&name, &rescuer, |recovery| {
+			if let Some(recovery) = recovery {
+				recovery.vouchers = BoundedVec::truncate_from(friends);
+			}
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), rescuer.clone());
+
+		let recovery = ActiveRecoveries::<T>::get(&name, &rescuer).expect("recovery was initiated");
+		assert_eq!(recovery.vouchers.len() as u32, T::MaxFriends::get());
+	}
+
+	#[benchmark]
+	fn claim_recovery(
+		n: Linear<1, MAX_NAME_LEN>,
+		p: Linear<1, MAX_PASSWORD_LEN>,
+		t: Linear<0, { T::MaxTweetsPerUser::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, lost) = other_user::<T>(n);
+		post_tweets::<T>(&lost, &name, &bytes(b'p', MAX_PASSWORD_LEN), t);
+		tipped_polls::<T>(&lost, t);
+		expiry_deposits::<T>(&lost, t);
+		devices::<T>(&lost, T::MaxDevices::get());
+		schedule_tweets::<T>(&lost, &name, &bytes(b'p', MAX_PASSWORD_LEN), T::MaxScheduledTweets::get());
+		let friends = recovery_config::<T>(&name, T::MaxFriends::get());
+		active_recovery::<T>(&name, &caller);
+		ActiveRecoveries::<T>::mutate(&name, &caller, |recovery| {
+			if let Some(recovery) = recovery {
+				recovery.vouchers = BoundedVec::truncate_from(friends);
+			}
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name.clone(), bytes(b'q', p));
+
+		assert_eq!(AccountByName::<T>::get(&name), Some(caller));
+	}
+
+	#[benchmark]
+	fn cancel_recovery(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		recovery_config::<T>(&name, T::MaxFriends::get());
+		let rescuer: T::AccountId = account("rescuer", 0, 0);
+		active_recovery::<T>(&name, &rescuer);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), password, rescuer.clone());
+
+		assert!(!ActiveRecoveries::<T>::contains_key(&name, &rescuer));
+	}
+
+	#[benchmark]
+	fn transfer_name(
+		n: Linear<1, MAX_NAME_LEN>,
+		p: Linear<1, MAX_PASSWORD_LEN>,
+		t: Linear<0, { T::MaxTweetsPerUser::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		post_tweets::<T>(&caller, &name, &password, t);
+		tipped_polls::<T>(&caller, t);
+		expiry_deposits::<T>(&caller, t);
+		devices::<T>(&caller, T::MaxDevices::get());
+		schedule_tweets::<T>(&caller, &name, &password, T::MaxScheduledTweets::get());
+		recovery_config::<T>(&name, T::MaxFriends::get());
+		let to: T::AccountId = account("recipient", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), password, to.clone());
+
+		assert_eq!(AccountByName::<T>::get(&name), Some(to));
+	}
+
+	#[benchmark]
+	fn list_name_for_sale(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		let price = <T::Currency as fungible::Inspect<_>>::minimum_balance();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), password, price);
+
+		assert_eq!(NamesForSale::<T>::get(&name), Some(price));
+	}
+
+	#[benchmark]
+	fn unlist_name(n: Linear<1, MAX_NAME_LEN>, p: Linear<1, MAX_PASSWORD_LEN>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, password) = register::<T>(&caller, n, p);
+		NamesForSale::<T>::insert(&name, <T::Currency as fungible::Inspect<_>>::minimum_balance());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), password);
+
+		assert!(!NamesForSale::<T>::contains_key(&name));
+	}
+
+	#[benchmark]
+	fn buy_name(
+		n: Linear<1, MAX_NAME_LEN>,
+		p: Linear<1, MAX_PASSWORD_LEN>,
+		t: Linear<0, { T::MaxTweetsPerUser::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (name, seller) = other_user::<T>(n);
+		post_tweets::<T>(&seller, &name, &bytes(b'p', MAX_PASSWORD_LEN), t);
+		tipped_polls::<T>(&seller, t);
+		expiry_deposits::<T>(&seller, t);
+		devices::<T>(&seller, T::MaxDevices::get());
+		schedule_tweets::<T>(&seller, &name, &bytes(b'p', MAX_PASSWORD_LEN), T::MaxScheduledTweets::get());
+		recovery_config::<T>(&name, T::MaxFriends::get());
+		let price = <T::Currency as fungible::Inspect<_>>::minimum_balance();
+		NamesForSale::<T>::insert(&name, price);
+		let balance = price.saturating_mul(2u32.into());
+		<T::Currency as fungible::Mutate<_>>::set_balance(&caller, balance);
+
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		_(RawOrigin::Signed(caller.clone()), name.clone(), price, bytes(b'q', p));
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert_eq!(AccountByName::<T>::get(&name), Some(caller));
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,227 @@
+//! Feeless posting for registered users.
+//!
+//! [`FeelessPosting`] wraps the runtime's transaction payment extension. `create_tweet`,
+//! `create_poll_tweet` and `create_expiring_tweet` made by an account with a registered name that
+//! is not suspended skip the wrapped extension, and with it the fee. Every other call pays as
+//! usual, so calls whose weight grows with a user's tweets are never free.
+//! In exchange they count against a per-account quota of `MaxPostsPerPeriod` calls every
+//! `QuotaPeriod` blocks. A feeless call provides a pool tag for the quota slot it takes, made of
+//! the sender, the window and the number of calls made in it so far. All feeless calls of an
+//! account compete for the same slot until one of them is in a block, so the pool holds at most
+//! one of them at a time, and calls over the quota are rejected when they are validated.
+//!
+//! Every call into this pallet, feeless or not, also gets a priority, tags and a longevity for the
+//! transaction pool:
+//!
+//! - Calls that walk over all of a user's tweets get [`BULK_PRIORITY`], others [`POST_PRIORITY`].
+//! - `create_tweet` and `create_poll_tweet` provide a tag made of the sender, the author and the
+//!   content, so the pool and therefore blocks only ever hold one copy of the same tweet. The
+//!   sender is part of it so nobody else can keep a tweet out of the pool by sending a copy of it
+//!   first, which would fail at dispatch anyway. If the author is not registered yet they require
+//!   the tag provided by the `create_user` call registering it, and wait in the pool until that
+//!   call is in a block.
+//! - `create_user` and `update_user_name` provide a tag for the name they claim, so only one
+//!   transaction claiming a name is in the pool at a time.
+//! - They stay valid for `PostLongevity` blocks.
+
+use crate::{AccountByName, Call, Config, Names, Pallet, PostQuota};
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use core::{fmt, marker::PhantomData};
+use frame_support::traits::{Get, IsSubType};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_core::hashing::blake2_256;
+use sp_runtime::{
+    traits::{
+        DispatchInfoOf, PostDispatchInfoOf, Saturating, SignedExtension, SignedExtensionMetadata,
+    },
+    transaction_validity::{
+        InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError,
+        ValidTransaction,
+    },
+    DispatchResult,
+};
+
+/// `InvalidTransaction::Custom` code for a feeless call made after the sender used up its quota.
+pub const POST_QUOTA_EXHAUSTED: u8 = 1;
+
+/// Transaction pool priority added to this pallet's calls.
+pub const POST_PRIORITY: TransactionPriority = 1_000;
+
+/// Transaction pool priority added to this pallet's calls that walk over all of a user's tweets.
+pub const BULK_PRIORITY: TransactionPriority = 100;
+
+/// Prefix of the transaction pool tags provided and required by this pallet's calls.
+const TAG_PREFIX: &str = "TemplateModule";
+
+/// Waives the fee charged by `S` for posting tweets when the sender has a registered name, and
+/// rate limits those calls instead.
+///
+/// `S` is the transaction payment extension, such as
+/// `pallet_transaction_payment::ChargeTransactionPayment` or
+/// `pallet_asset_tx_payment::ChargeAssetTxPayment`. The wrapper encodes exactly like `S`
+/// and reports `S`'s metadata, so clients keep building extrinsics as they did before.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct FeelessPosting<T, S>(pub S, PhantomData<T>);
+
+impl<T, S> From<S> for FeelessPosting<T, S> {
+    fn from(inner: S) -> Self {
+        Self(inner, PhantomData)
+    }
+}
+
+impl<T, S: fmt::Debug> fmt::Debug for FeelessPosting<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FeelessPosting<{:?}>", self.0)
+    }
+}
+
+impl<T: Config, S> FeelessPosting<T, S>
+where
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+    /// Whether `call` from `who` is free of charge and subject to the posting quota instead.
+    fn is_feeless(
+        who: &T::AccountId,
+        call: &<T as frame_system::Config>::RuntimeCall,
+    ) -> bool {
+        matches!(
+            call.is_sub_type(),
+            Some(
+                Call::create_tweet { .. } |
+                    Call::create_poll_tweet { .. } |
+                    Call::create_expiring_tweet { .. }
+            )
+        ) && Names::<T>::contains_key(who) &&
+            Pallet::<T>::active_suspension(who).is_none()
+    }
+
+    /// The quota entry for `who` after making one more feeless call, or an error if `who` has
+    /// no calls left in the current window.
+    fn next_quota(
+        who: &T::AccountId,
+    ) -> Result<(BlockNumberFor<T>, u32), TransactionValidityError> {
+        let now = frame_system::Pallet::<T>::block_number();
+        let (window_start, used) = match PostQuota::<T>::get(who) {
+            Some((start, used)) if now < start.saturating_add(T::QuotaPeriod::get()) =>
+                (start, used),
+            _ => (now, 0),
+        };
+
+        if used >= T::MaxPostsPerPeriod::get() {
+            return Err(InvalidTransaction::Custom(POST_QUOTA_EXHAUSTED).into());
+        }
+        Ok((window_start, used.saturating_add(1)))
+    }
+
+    /// Priority, tags and longevity of `call` in the transaction pool.
+    fn pool_validity(who: &T::AccountId, call: &Call<T>) -> TransactionValidity {
+        let priority = match call {
+            Call::get_user_tweets { .. } |
+            Call::update_user_name { .. } |
+            Call::delete_user { .. } |
+            Call::claim_recovery { .. } |
+            Call::transfer_name { .. } |
+            Call::buy_name { .. } => BULK_PRIORITY,
+            _ => POST_PRIORITY,
+        };
+        let validity = ValidTransaction::with_tag_prefix(TAG_PREFIX)
+            .priority(priority)
+            .longevity(T::PostLongevity::get())
+            .propagate(true);
+
+        match call {
+            Call::create_user { name, .. } | Call::update_user_name { new_name: name, .. } =>
+                validity.and_provides((b"name", name)).build(),
+            Call::create_tweet { name, content, .. } |
+            Call::create_poll_tweet { name, content, .. } => {
+                let validity = validity.and_provides((b"tweet", who, name, blake2_256(content)));
+                if AccountByName::<T>::contains_key(name) {
+                    validity.build()
+                } else {
+                    validity.and_requires((b"name", name)).build()
+                }
+            },
+            _ => validity.build(),
+        }
+    }
+}
+
+impl<T, S> SignedExtension for FeelessPosting<T, S>
+where
+    T: Config + Send + Sync,
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+    S: SignedExtension<
+        AccountId = T::AccountId,
+        Call = <T as frame_system::Config>::RuntimeCall,
+    >,
+{
+    const IDENTIFIER: &'static str = S::IDENTIFIER;
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::RuntimeCall;
+    type AdditionalSigned = S::AdditionalSigned;
+    /// `None` if the call was feeless, the wrapped extension's `Pre` otherwise.
+    type Pre = Option<S::Pre>;
+
+    fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+        self.0.additional_signed()
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> TransactionValidity {
+        let Some(pallet_call) = call.is_sub_type() else {
+            return self.0.validate(who, call, info, len);
+        };
+
+        let validity = if Self::is_feeless(who, call) {
+            let slot = Self::next_quota(who)?;
+            ValidTransaction::with_tag_prefix(TAG_PREFIX)
+                .and_provides((b"quota", who, slot))
+                .build()?
+        } else {
+            self.0.validate(who, call, info, len)?
+        };
+        Ok(validity.combine_with(Self::pool_validity(who, pallet_call)?))
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        if Self::is_feeless(who, call) {
+            PostQuota::<T>::insert(who, Self::next_quota(who)?);
+            Ok(None)
+        } else {
+            self.0.pre_dispatch(who, call, info, len).map(Some)
+        }
+    }
+
+    fn post_dispatch(
+        pre: Option<Self::Pre>,
+        info: &DispatchInfoOf<Self::Call>,
+        post_info: &PostDispatchInfoOf<Self::Call>,
+        len: usize,
+        result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        match pre {
+            // Feeless, there is nothing to refund.
+            Some(None) => Ok(()),
+            Some(Some(pre)) => S::post_dispatch(Some(pre), info, post_info, len, result),
+            None => S::post_dispatch(None, info, post_info, len, result),
+        }
+    }
+
+    fn metadata() -> Vec<SignedExtensionMetadata> {
+        S::metadata()
+    }
+}
@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 use pallet_timestamp as timestamp;
 
@@ -13,27 +15,238 @@ mod tests;
 mod benchmarking;
 pub mod weights;
 pub use weights::*;
+pub mod extension;
+pub use extension::*;
+pub mod migrations;
+
+/// Judgements on accounts from an identity system such as `pallet_identity`, shown in profiles
+/// and optionally required to register a name.
+pub trait IdentityJudgement<AccountId> {
+    /// The judgement on `who`'s identity, if it has a judged identity.
+    fn judgement(who: &AccountId) -> Option<Judgement>;
+}
+
+/// No identity system, so nobody has a judged identity.
+impl<AccountId> IdentityJudgement<AccountId> for () {
+    fn judgement(_: &AccountId) -> Option<Judgement> {
+        None
+    }
+}
 
+/// Sets up the NFTs the benchmarks of this pallet set as avatars.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, CollectionId, ItemId> {
+    /// Mint an NFT owned by `owner`.
+    fn mint_nft(owner: &AccountId) -> (CollectionId, ItemId);
+}
+
+// The call dispatch code generated for `DispatchResultWithPostInfo` calls converts the result
+// into itself.
+#[allow(clippy::useless_conversion)]
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::{pallet_prelude::*, BoundedVec};
+    use alloc::{vec, vec::Vec};
+    use frame_support::{pallet_prelude::*, weights::WeightMeter, BoundedVec};
     use frame_system::pallet_prelude::*;
-    use frame_support::traits::ConstU32;
+    use frame_support::traits::{
+        fungible::{self, Mutate, MutateHold},
+        fungibles,
+        nonfungibles_v2::{self, Inspect as _},
+        schedule::{self, v3::Named as ScheduleNamed, DispatchTime},
+        tokens::{Fortitude, Precision, Preservation, Restriction},
+        Bounded, ConstU32,
+    };
     use sp_core::hashing::blake2_256;
-    use sp_runtime::traits::SaturatedConversion;
+    use sp_runtime::traits::{SaturatedConversion, Saturating, Zero};
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    /// Balance of the currency deposits are held in.
+    pub type BalanceOf<T> = <<T as Config>::Currency as fungible::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    /// ID of an asset of `Config::Assets`.
+    pub type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::AssetId;
+
+    /// Balance of an asset of `Config::Assets`.
+    pub type AssetBalanceOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
     #[pallet::config]
     pub trait Config: frame_system::Config + timestamp::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type WeightInfo: WeightInfo;
+
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Currency deposits are held in and tips are paid in.
+        type Currency: fungible::Mutate<Self::AccountId>
+            + fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// Assets tweets can be tipped in besides `Currency`, typically `pallet_assets`.
+        type Assets: fungibles::Mutate<Self::AccountId>;
+
+        /// Maximum number of different assets a single tweet can be tipped in.
+        #[pallet::constant]
+        type MaxTipAssets: Get<u32>;
+
+        /// Identifies a collection of `Nfts`.
+        type NftCollectionId: Parameter + MaxEncodedLen + Copy;
+
+        /// Identifies an item within a collection of `Nfts`.
+        type NftId: Parameter + MaxEncodedLen + Copy;
+
+        /// NFTs users can show as their avatar, typically `pallet_nfts`.
+        type Nfts: nonfungibles_v2::Inspect<
+            Self::AccountId,
+            CollectionId = Self::NftCollectionId,
+            ItemId = Self::NftId,
+        >;
+
+        /// Maximum number of avatars `on_idle` checks for NFTs that changed hands per block.
+        #[pallet::constant]
+        type MaxAvatarChecksPerBlock: Get<u32>;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::NftCollectionId, Self::NftId>;
+
+        /// Deposit held from the sender of a direct message until the message is deleted.
+        #[pallet::constant]
+        type MessageDeposit: Get<BalanceOf<Self>>;
+
+        /// Maximum number of messages stored in a conversation between two users.
+        #[pallet::constant]
+        type MaxMessagesPerConversation: Get<u32>;
+
+        /// Maximum number of polls that can end in the same block. Bounds the weight of closing
+        /// them in `on_initialize`.
+        #[pallet::constant]
+        type MaxPollsEndingPerBlock: Get<u32>;
+
+        /// Maximum number of blocks a poll can stay open for.
+        #[pallet::constant]
+        type MaxPollDuration: Get<BlockNumberFor<Self>>;
+
+        /// The overarching call type, for the calls handed to `Scheduler`.
+        type RuntimeCall: From<Call<Self>>
+            + Encode
+            + IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// The caller origin, for the origin scheduled calls are dispatched with.
+        type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+        /// Publishes tweets queued with `schedule_tweet` once they are due.
+        type Scheduler: ScheduleNamed<
+            BlockNumberFor<Self>,
+            <Self as Config>::RuntimeCall,
+            Self::PalletsOrigin,
+        >;
+
+        /// Maximum number of tweets a user can have scheduled at the same time.
+        #[pallet::constant]
+        type MaxScheduledTweets: Get<u32>;
+
+        /// Maximum number of tweets a user can have scheduled for the same block, so that nobody
+        /// fills up the scheduler agenda of a block on their own.
+        #[pallet::constant]
+        type MaxScheduledTweetsPerBlock: Get<u32>;
+
+        /// Maximum number of tweets that can expire in the same block.
+        #[pallet::constant]
+        type MaxExpiringPerBlock: Get<u32>;
+
+        /// Deposit held from the author of an expiring tweet until the tweet is removed, to pay
+        /// for the work `on_idle` does to remove it.
+        #[pallet::constant]
+        type ExpiringTweetDeposit: Get<BalanceOf<Self>>;
+
+        /// Maximum number of their own tweets a user can pin to their profile.
+        #[pallet::constant]
+        type MaxPinnedTweets: Get<u32>;
+
+        /// Maximum number of tweets an account can bookmark.
+        #[pallet::constant]
+        type MaxBookmarks: Get<u32>;
+
+        /// Maximum number of lists a user can create.
+        #[pallet::constant]
+        type MaxListsPerUser: Get<u32>;
+
+        /// Maximum number of users in a list.
+        #[pallet::constant]
+        type MaxListMembers: Get<u32>;
+
+        /// Maximum number of device keys an account can add, expired ones included.
+        #[pallet::constant]
+        type MaxDevices: Get<u32>;
+
+        /// Maximum number of friends in a name's recovery config.
+        #[pallet::constant]
+        type MaxFriends: Get<u32>;
+
+        /// Deposit held from whoever initiates the recovery of a name. It goes back to them once
+        /// they claim the name, and to the name's owner if the owner cancels the recovery.
+        #[pallet::constant]
+        type RecoveryDeposit: Get<BalanceOf<Self>>;
+
+        /// Maximum number of tweets a user can ever post. Bounds the weight of the calls that
+        /// walk over all of a user's tweets, such as `update_user_name` and `delete_user`.
+        #[pallet::constant]
+        type MaxTweetsPerUser: Get<u32>;
+
+        /// Number of tweets a registered user can post without fees per `QuotaPeriod`, see
+        /// [`FeelessPosting`].
+        #[pallet::constant]
+        type MaxPostsPerPeriod: Get<u32>;
+
+        /// Length in blocks of the window `MaxPostsPerPeriod` applies to.
+        #[pallet::constant]
+        type QuotaPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks this pallet's calls stay valid in the transaction pool.
+        #[pallet::constant]
+        type PostLongevity: Get<TransactionLongevity>;
+
+        /// Maximum number of `@name` mentions in a tweet.
+        #[pallet::constant]
+        type MaxMentions: Get<u32>;
+
+        /// Origin that works through the moderation queue: it hides and removes reported tweets,
+        /// suspends names and rules on appeals.
+        type ModeratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of open reports against a single tweet.
+        #[pallet::constant]
+        type MaxReportsPerTweet: Get<u32>;
+
+        /// Origin that suspends users for a set time, lifts suspensions and bans users.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of registrars, and so of judgements on a single name.
+        #[pallet::constant]
+        type MaxRegistrars: Get<u32>;
+
+        /// Identity system whose judgement on an account is shown in its profile.
+        type Identity: IdentityJudgement<Self::AccountId>;
+
+        /// Whether registering a name requires an identity judged `Reasonable` or `KnownGood` by
+        /// `Identity`.
+        #[pallet::constant]
+        type RequireIdentity: Get<bool>;
     }
 
     // Birthday struct to store date information
-    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, Debug)]
+    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, Debug, PartialEq, Eq)]
     pub struct Birthday {
         pub year: u16,
         pub month: u8,
@@ -42,10 +255,201 @@ pub mod pallet {
 
     // Modified Tweet struct to use name instead of AccountId
     #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, Debug)]
-    pub struct Tweet {
+    pub struct Tweet<BlockNumber> {
         pub name: BoundedVec<u8, ConstU32<256>>,
         pub content: BoundedVec<u8, ConstU32<280>>,
         pub timestamp: u64,
+        /// The block from which on the tweet is removed, see `create_expiring_tweet`.
+        pub expires_at: Option<BlockNumber>,
+    }
+
+    // A report filed against a tweet
+    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, Debug, PartialEq, Eq)]
+    pub struct Report<AccountId> {
+        pub reporter: AccountId,
+        pub reason: BoundedVec<u8, ConstU32<256>>,
+    }
+
+    // Moderation decision a user can appeal against
+    #[derive(Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, Debug, PartialEq, Eq)]
+    pub enum AppealSubject {
+        /// The hiding of one of the user's tweets.
+        Tweet(u32),
+        /// The suspension of the user's name.
+        Suspension,
+    }
+
+    // Why an account is kept from posting
+    #[derive(Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, Debug, PartialEq, Eq)]
+    pub enum Suspension<BlockNumber> {
+        /// Suspended by a moderator until the suspension is lifted on appeal.
+        Indefinite,
+        /// Suspended by an admin until the given block.
+        Until(BlockNumber),
+        /// Banned by an admin. Bans can't be appealed.
+        Banned,
+    }
+
+    // A registrar's verdict on whether a name belongs to who it claims to be, like the
+    // judgements of `pallet_identity`
+    #[derive(Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, Debug, PartialEq, Eq)]
+    pub enum Judgement {
+        /// The registrar found no reason to doubt the name.
+        Reasonable,
+        /// The registrar verified who is behind the name.
+        KnownGood,
+        /// The name impersonates someone else.
+        Erroneous,
+    }
+
+    /// Reasons this pallet holds funds for.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Deposit for a stored direct message.
+        MessageDeposit,
+        /// Deposit for an active recovery of a name.
+        RecoveryDeposit,
+        /// Deposit for an expiring tweet.
+        ExpiringTweetDeposit,
+    }
+
+    // An end-to-end encrypted direct message. The pallet never sees the plaintext: the sender
+    // derives a shared secret from `ephemeral_pubkey` and the recipient's messaging key.
+    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, Debug, PartialEq, Eq)]
+    pub struct Message<AccountId, Balance, BlockNumber> {
+        pub from: AccountId,
+        pub ciphertext: BoundedVec<u8, ConstU32<1024>>,
+        pub nonce: [u8; 24],
+        /// X25519 public key of the sender's one-off key pair for this message.
+        pub ephemeral_pubkey: [u8; 32],
+        pub sent_at: BlockNumber,
+        /// Held from `from` until the message is deleted.
+        pub deposit: Balance,
+        /// `MessageDepositRound` of `from` when the message was sent. The deposit was released
+        /// already if the round moved on since.
+        pub deposit_round: u32,
+    }
+
+    // A poll attached to a tweet. Closed polls keep their final tallies.
+    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, Debug, PartialEq, Eq)]
+    pub struct Poll<BlockNumber> {
+        /// Unique across all polls ever created, see `PollVotes`.
+        pub index: u32,
+        pub options: BoundedVec<BoundedVec<u8, ConstU32<64>>, ConstU32<4>>,
+        /// Votes per option, in the order of `options`.
+        pub tallies: BoundedVec<u32, ConstU32<4>>,
+        pub ends_at: BlockNumber,
+        pub closed: bool,
+    }
+
+    // A curated list of users, see `create_list`.
+    #[derive(
+        frame_support::CloneNoBound,
+        frame_support::PartialEqNoBound,
+        frame_support::EqNoBound,
+        frame_support::RuntimeDebugNoBound,
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct UserList<T: Config> {
+        /// Accounts rather than names, so that members keep their place when they rename.
+        pub members: BoundedVec<T::AccountId, T::MaxListMembers>,
+        /// Private lists are only shown to their owner by the runtime API. Like all storage,
+        /// their members can still be read from the chain state.
+        pub private: bool,
+    }
+
+    // What a device key may do for the account that added it, see `add_device`
+    #[derive(Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, Debug, PartialEq, Eq)]
+    pub enum DeviceScope {
+        /// Post tweets, polls and expiring tweets, and schedule tweets.
+        Post,
+        /// Delete tweets and cancel scheduled tweets.
+        Delete,
+        /// Pin tweets, manage lists and set the avatar.
+        Profile,
+    }
+
+    // A device key added with `add_device`
+    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, Debug, PartialEq, Eq)]
+    pub struct Device<BlockNumber> {
+        pub scopes: BoundedVec<DeviceScope, ConstU32<3>>,
+        /// The block from which on the device can no longer act for the account.
+        pub expires_at: Option<BlockNumber>,
+    }
+
+    // Who can recover a name whose account key is lost, see `set_recovery`
+    #[derive(
+        frame_support::CloneNoBound,
+        frame_support::PartialEqNoBound,
+        frame_support::EqNoBound,
+        frame_support::RuntimeDebugNoBound,
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct RecoveryConfig<T: Config> {
+        /// Accounts of the friends' names at the time the config was set.
+        pub friends: BoundedVec<T::AccountId, T::MaxFriends>,
+        /// Number of friends who have to vouch for a recovery.
+        pub threshold: u32,
+        /// Number of blocks between initiating and claiming a recovery.
+        pub delay: BlockNumberFor<T>,
+    }
+
+    // A recovery of a name initiated with `initiate_recovery`
+    #[derive(
+        frame_support::CloneNoBound,
+        frame_support::PartialEqNoBound,
+        frame_support::EqNoBound,
+        frame_support::RuntimeDebugNoBound,
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct ActiveRecovery<T: Config> {
+        pub started_at: BlockNumberFor<T>,
+        /// Held from the account the name is recovered to.
+        pub deposit: BalanceOf<T>,
+        /// Friends who vouched for the recovery.
+        pub vouchers: BoundedVec<T::AccountId, T::MaxFriends>,
+    }
+
+    // A tweet queued with `schedule_tweet`.
+    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, Debug, PartialEq, Eq)]
+    pub struct ScheduledTweet<BlockNumber> {
+        pub id: u32,
+        pub content: BoundedVec<u8, ConstU32<280>>,
+        /// The block it is published in.
+        pub at: BlockNumber,
+    }
+
+    /// A user's profile as shown to clients through the runtime API, see [`Pallet::profile`].
+    #[derive(Clone, Encode, Decode, TypeInfo, Debug, PartialEq, Eq)]
+    pub struct Profile<AccountId, NftCollectionId, NftId> {
+        pub account: AccountId,
+        pub name: BoundedVec<u8, ConstU32<256>>,
+        pub birthday: Option<Birthday>,
+        pub tweet_count: u32,
+        /// Judgements on the name by the current registrars.
+        pub judgements: Vec<(AccountId, Judgement)>,
+        /// Judgement on the account's identity by `Config::Identity`.
+        pub identity: Option<Judgement>,
+        /// X25519 public key to encrypt direct messages to the user with.
+        pub messaging_key: Option<[u8; 32]>,
+        /// Collection and item of the NFT the user shows as their avatar, as long as they still
+        /// own it.
+        pub avatar: Option<(NftCollectionId, NftId)>,
     }
 
     // Store name per account
@@ -99,163 +503,2714 @@ pub mod pallet {
         BoundedVec<u8, ConstU32<256>>, // Name as primary key
         Twox64Concat,
         u32,
-        Tweet,
+        Tweet<BlockNumberFor<T>>,
         OptionQuery
     >;
 
-    // Modified tweet count storage to use name
+    // Expiring tweets per block they expire at, as (author account, tweet ID). Entries of tweets
+    // deleted before they expire stay until `on_idle` gets to them.
     #[pallet::storage]
-    pub type TweetCount<T: Config> = StorageMap<
+    pub type ExpiringAt<T: Config> = StorageMap<
         _,
         Twox64Concat,
-        BoundedVec<u8, ConstU32<256>>, // Name as key
-        u32,
+        BlockNumberFor<T>,
+        BoundedVec<(T::AccountId, u32), T::MaxExpiringPerBlock>,
         ValueQuery
     >;
 
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        NameStored {
-            name: BoundedVec<u8, ConstU32<256>>,
-            who: T::AccountId,
-        },
-        BirthdayStored {
-            name: BoundedVec<u8, ConstU32<256>>,
-            year: u16,
-            month: u8,
-            day: u8,
-        },
-        PasswordHashed {
-            name: BoundedVec<u8, ConstU32<256>>,
-        },
-        LoginSuccessful {
-            name: BoundedVec<u8, ConstU32<256>>,
-            who: T::AccountId,
-        },
-        LoginFailed {
-            name: BoundedVec<u8, ConstU32<256>>,
-        },
-        TweetCreated {
-            name: BoundedVec<u8, ConstU32<256>>,
-            tweet_id: u32,
-            timestamp: u64,
-        },
-    }
+    // First block whose expiring tweets `on_idle` has not removed yet, set once the first
+    // expiring tweet is posted
+    #[pallet::storage]
+    pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
 
-    #[pallet::error]
-    pub enum Error<T> {
-        NoneValue,
-        StorageOverflow,
-        UserNotFound,
-        InvalidCredentials,
-        NameAlreadyTaken,
-        TweetTooLong,
-        NotAuthorized,
-        InvalidBirthday,
-        InvalidCaller,
-        TweetNotFound,
-    }
+    // Deposit held for an expiring tweet, keyed by author account and tweet ID
+    #[pallet::storage]
+    pub type ExpiryDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        u32,
+        BalanceOf<T>,
+        OptionQuery
+    >;
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        #[pallet::call_index(0)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_user())]
-        pub fn create_user(
-            origin: OriginFor<T>,
-            name: BoundedVec<u8, ConstU32<256>>,
-            password: BoundedVec<u8, ConstU32<256>>,
-            year: u16,
-            month: u8,
-            day: u8,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+    // IDs of the tweets users pinned to their profile, per account
+    #[pallet::storage]
+    pub type PinnedTweets<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<u32, T::MaxPinnedTweets>,
+        ValueQuery
+    >;
 
-            // Validate birthday
-            ensure!(month <= 12 && month > 0, Error::<T>::InvalidBirthday);
-            ensure!(day <= 31 && day > 0, Error::<T>::InvalidBirthday);
-            ensure!(year >= 1920 && year <= 2006, Error::<T>::InvalidBirthday);
+    // Tweets bookmarked per account as (author account, tweet ID), oldest first
+    #[pallet::storage]
+    pub type Bookmarks<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<(T::AccountId, u32), T::MaxBookmarks>,
+        ValueQuery
+    >;
 
-            // Ensure name isn't already taken
-            ensure!(!AccountByName::<T>::contains_key(&name), Error::<T>::NameAlreadyTaken);
+    // Accounts that bookmarked a tweet, keyed by (author account, tweet ID) and bookmarker
+    #[pallet::storage]
+    pub type BookmarkedBy<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        (T::AccountId, u32),
+        Twox64Concat,
+        T::AccountId,
+        (),
+        OptionQuery
+    >;
 
-            // Store name mappings
-            Names::<T>::insert(&who, name.clone());
-            AccountByName::<T>::insert(&name, who.clone());
+    // Lists created by users, keyed by owner name and list name
+    #[pallet::storage]
+    pub type Lists<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BoundedVec<u8, ConstU32<256>>, // Owner name as primary key
+        Twox64Concat,
+        BoundedVec<u8, ConstU32<64>>, // List name as secondary key
+        UserList<T>,
+        OptionQuery
+    >;
 
-            // Store birthday
-            let birthday = Birthday { year, month, day };
-            Birthdays::<T>::insert(&name, 0, birthday);
+    // Total amount tipped to a tweet, keyed by author account and tweet ID
+    #[pallet::storage]
+    pub type Tips<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        u32,
+        BalanceOf<T>,
+        ValueQuery
+    >;
 
-            // Hash and store password
-            let password_hash = blake2_256(&password[..]);
-            PasswordHash::<T>::insert(&name, 0, password_hash);
+    // Total amounts tipped to a tweet per asset, keyed by author account and tweet ID
+    #[pallet::storage]
+    pub type AssetTips<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        u32,
+        BoundedVec<(AssetIdOf<T>, AssetBalanceOf<T>), T::MaxTipAssets>,
+        ValueQuery
+    >;
 
-            // Initialize tweet count
-            TweetCount::<T>::insert(&name, 0);
+    // Number of lists per owner name
+    #[pallet::storage]
+    pub type ListCount<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BoundedVec<u8, ConstU32<256>>,
+        u32,
+        ValueQuery
+    >;
 
-            // Emit events
-            Self::deposit_event(Event::NameStored { name: name.clone(), who: who.clone() });
-            Self::deposit_event(Event::BirthdayStored { name: name.clone(), year, month, day });
-            Self::deposit_event(Event::PasswordHashed { name: name.clone() });
+    // Deleted tweets whose bookmarks `on_idle` still has to remove, as (author account, tweet ID)
+    #[pallet::storage]
+    pub type StaleBookmarks<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        (T::AccountId, u32),
+        (),
+        OptionQuery
+    >;
 
-            Ok(())
-        }
+    // Modified tweet count storage to use name
+    #[pallet::storage]
+    pub type TweetCount<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BoundedVec<u8, ConstU32<256>>, // Name as key
+        u32,
+        ValueQuery
+    >;
 
-        #[pallet::call_index(1)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::verify_user())]
-        pub fn verify_user(
-            origin: OriginFor<T>,
-            name: BoundedVec<u8, ConstU32<256>>,
-            password: BoundedVec<u8, ConstU32<256>>
-        ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+    // Accounts blocked by an account. Kept per account rather than per name so renaming does not
+    // get anyone out of a block.
+    #[pallet::storage]
+    pub type Blocked<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId, // Blocker
+        Twox64Concat,
+        T::AccountId, // Blocked
+        (),
+        OptionQuery
+    >;
 
-            // Get account by name
-            let account = AccountByName::<T>::get(&name)
-                .ok_or(Error::<T>::UserNotFound)?;
+    // Accounts muted by an account. Only clients act on mutes, see `muted_names`.
+    #[pallet::storage]
+    pub type Muted<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        (),
+        OptionQuery
+    >;
 
-            // Get stored password hash
-            let stored_hash = PasswordHash::<T>::get(&name,0)
-                .ok_or(Error::<T>::UserNotFound)?;
+    // Open reports per tweet. This is the moderation queue: entries leave it when a moderator
+    // hides or removes the tweet or dismisses its reports. Keyed by the author's account rather
+    // than name so renaming does not clear a queue entry.
+    #[pallet::storage]
+    pub type Reports<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId, // Author
+        Twox64Concat,
+        u32, // Tweet ID
+        BoundedVec<Report<T::AccountId>, T::MaxReportsPerTweet>,
+        OptionQuery
+    >;
 
-            // Hash the provided password
-            let password_hash = blake2_256(&password[..]);
+    // Tweets hidden by a moderator. They stay in `Tweets` so they can be reinstated on appeal.
+    #[pallet::storage]
+    pub type HiddenTweets<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId, // Author
+        Twox64Concat,
+        u32, // Tweet ID
+        (),
+        OptionQuery
+    >;
 
-            // Compare hashes using constant-time comparison
-            if password_hash == stored_hash {
-                Self::deposit_event(Event::LoginSuccessful { name, who: account });
-                Ok(())
-            } else {
-                Self::deposit_event(Event::LoginFailed { name });
-                Err(Error::<T>::InvalidCredentials.into())
-            }
-        }
+    // Suspended and banned accounts. They can't tweet, rename or register a new name, see
+    // `active_suspension`. Expired suspensions stay here until the account is suspended again.
+    #[pallet::storage]
+    pub type Suspended<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Suspension<BlockNumberFor<T>>,
+        OptionQuery
+    >;
 
-        #[pallet::call_index(2)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_tweet())]
-        pub fn create_tweet(
+    // Pending appeals with the statement the user made
+    #[pallet::storage]
+    pub type Appeals<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        AppealSubject,
+        BoundedVec<u8, ConstU32<256>>,
+        OptionQuery
+    >;
+
+    // Accounts allowed to give judgements, managed by root
+    #[pallet::storage]
+    pub type Registrars<T: Config> = StorageValue<
+        _,
+        BoundedVec<T::AccountId, T::MaxRegistrars>,
+        ValueQuery
+    >;
+
+    // Registrar judgements per name. A judgement vouches for the pairing of a name and an
+    // account, so renaming or deleting the user clears them.
+    #[pallet::storage]
+    pub type Judgements<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BoundedVec<u8, ConstU32<256>>,
+        BoundedVec<(T::AccountId, Judgement), T::MaxRegistrars>,
+        ValueQuery
+    >;
+
+    // X25519 public keys users receive direct messages with
+    #[pallet::storage]
+    pub type MessagingKeys<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        [u8; 32],
+        OptionQuery
+    >;
+
+    // Device keys per account and device account, see `add_device`
+    #[pallet::storage]
+    pub type Devices<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        Device<BlockNumberFor<T>>,
+        OptionQuery
+    >;
+
+    // Number of device keys per account
+    #[pallet::storage]
+    pub type DeviceCount<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        u32,
+        ValueQuery
+    >;
+
+    // Recovery configs per name, see `set_recovery`
+    #[pallet::storage]
+    pub type Recoveries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BoundedVec<u8, ConstU32<256>>,
+        RecoveryConfig<T>,
+        OptionQuery
+    >;
+
+    // Recoveries in progress, keyed by the name and the account it is recovered to
+    #[pallet::storage]
+    pub type ActiveRecoveries<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BoundedVec<u8, ConstU32<256>>,
+        Twox64Concat,
+        T::AccountId,
+        ActiveRecovery<T>,
+        OptionQuery
+    >;
+
+    // Number of recoveries in progress per name that at least one friend vouched for. Only
+    // those keep the owner from renaming, deleting, handing over or reconfiguring the name, so
+    // anyone can initiate a recovery without freezing the name
+    #[pallet::storage]
+    pub type VouchedRecoveries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BoundedVec<u8, ConstU32<256>>,
+        u32,
+        ValueQuery
+    >;
+
+    // Asking prices of the names listed for sale, see `list_name_for_sale`
+    #[pallet::storage]
+    pub type NamesForSale<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BoundedVec<u8, ConstU32<256>>,
+        BalanceOf<T>,
+        OptionQuery
+    >;
+
+    // NFT each account shows as its avatar, see `set_avatar`. `Nfts` has no hook this pallet
+    // could use to learn about transfers, so `on_idle` walks over the avatars a few at a time
+    // and removes those whose NFT changed hands. Until it gets to one, `profile` hides it and
+    // anyone can remove it with `clear_avatar`
+    #[pallet::storage]
+    pub type Avatars<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        (T::NftCollectionId, T::NftId),
+        OptionQuery
+    >;
+
+    // Raw storage key of the last avatar `on_idle` checked, unset when the next check starts
+    // over from the first avatar
+    #[pallet::storage]
+    pub type AvatarCursor<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+    // Direct messages per conversation, keyed by the two participants' accounts in ascending
+    // order (see `conversation`) and the message ID
+    #[pallet::storage]
+    pub type Messages<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        (T::AccountId, T::AccountId),
+        Twox64Concat,
+        u32,
+        Message<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery
+    >;
+
+    // Number of times `delete_user` released all message deposits of an account, see
+    // `Message::deposit_round`
+    #[pallet::storage]
+    pub type MessageDepositRound<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        u32,
+        ValueQuery
+    >;
+
+    // Per conversation as (ID of the next message, messages currently stored)
+    #[pallet::storage]
+    pub type MessageCount<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        (T::AccountId, T::AccountId),
+        (u32, u32),
+        ValueQuery
+    >;
+
+    // Polls attached to tweets, keyed by the author's account and the tweet ID
+    #[pallet::storage]
+    pub type Polls<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        u32,
+        Poll<BlockNumberFor<T>>,
+        OptionQuery
+    >;
+
+    // Index of the next poll
+    #[pallet::storage]
+    pub type NextPollIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    // The option each voter ID picked, keyed by poll index so votes never carry over to a later
+    // poll on a reused tweet ID
+    #[pallet::storage]
+    pub type PollVotes<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Twox64Concat,
+        u64,
+        u8,
+        OptionQuery
+    >;
+
+    // The ID the name registered to an account votes under. Assigned on its first vote and
+    // moved along with the name, so a name votes once per poll whichever account holds it. Kept
+    // when the user is deleted, so registering again from the same account cannot vote twice
+    #[pallet::storage]
+    pub type VoterIds<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u64, OptionQuery>;
+
+    // The next voter ID to assign
+    #[pallet::storage]
+    pub type NextVoterId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    // Polls to close at the start of each block as (author account, tweet ID)
+    #[pallet::storage]
+    pub type PollsEndingAt<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(T::AccountId, u32), T::MaxPollsEndingPerBlock>,
+        ValueQuery
+    >;
+
+    // Tweets waiting for `Scheduler` to publish them, per account
+    #[pallet::storage]
+    pub type ScheduledTweets<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<ScheduledTweet<BlockNumberFor<T>>, T::MaxScheduledTweets>,
+        ValueQuery
+    >;
+
+    // ID of the next tweet an account schedules. Kept when the user is deleted so IDs, and
+    // with them the scheduler task names, are never reused.
+    #[pallet::storage]
+    pub type NextScheduleId<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        u32,
+        ValueQuery
+    >;
+
+    // Feeless calls made per account as (first block of the current window, calls made in it)
+    #[pallet::storage]
+    pub type PostQuota<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        (BlockNumberFor<T>, u32),
+        OptionQuery
+    >;
+
+    /// A user registered at genesis as `(account, name, password, (year, month, day))`.
+    pub type GenesisUser<AccountId> = (AccountId, Vec<u8>, Vec<u8>, (u16, u8, u8));
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Users registered at genesis.
+        pub users: Vec<GenesisUser<T::AccountId>>,
+        /// Tweets posted at genesis as `(name, content)`; the name must be in `users`.
+        pub tweets: Vec<(Vec<u8>, Vec<u8>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (who, name, password, (year, month, day)) in &self.users {
+                let name: BoundedVec<u8, ConstU32<256>> =
+                    name.clone().try_into().expect("genesis user name is too long");
+                assert!(!AccountByName::<T>::contains_key(&name), "duplicate genesis user name");
+                assert!(!Names::<T>::contains_key(who), "genesis account already has a name");
+
+                Names::<T>::insert(who, name.clone());
+                AccountByName::<T>::insert(&name, who.clone());
+                Birthdays::<T>::insert(&name, 0, Birthday { year: *year, month: *month, day: *day });
+                PasswordHash::<T>::insert(&name, 0, blake2_256(password));
+                TweetCount::<T>::insert(&name, 0);
+            }
+
+            for (name, content) in &self.tweets {
+                let name: BoundedVec<u8, ConstU32<256>> =
+                    name.clone().try_into().expect("genesis tweet name is too long");
+                let content: BoundedVec<u8, ConstU32<280>> =
+                    content.clone().try_into().expect("genesis tweet content is too long");
+                assert!(AccountByName::<T>::contains_key(&name), "genesis tweet author is not a user");
+
+                let tweet_id = TweetCount::<T>::get(&name);
+                assert!(
+                    tweet_id < T::MaxTweetsPerUser::get(),
+                    "too many genesis tweets for one user"
+                );
+                Tweets::<T>::insert(
+                    &name,
+                    tweet_id,
+                    Tweet { name: name.clone(), content, timestamp: 0, expires_at: None },
+                );
+                TweetCount::<T>::insert(&name, tweet_id.saturating_add(1));
+            }
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        NameStored {
+            name: BoundedVec<u8, ConstU32<256>>,
+            who: T::AccountId,
+        },
+        BirthdayStored {
+            name: BoundedVec<u8, ConstU32<256>>,
+            year: u16,
+            month: u8,
+            day: u8,
+        },
+        PasswordHashed {
+            name: BoundedVec<u8, ConstU32<256>>,
+        },
+        LoginSuccessful {
+            name: BoundedVec<u8, ConstU32<256>>,
+            who: T::AccountId,
+        },
+        LoginFailed {
+            name: BoundedVec<u8, ConstU32<256>>,
+        },
+        TweetCreated {
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+            timestamp: u64,
+        },
+        UserBlocked {
+            name: BoundedVec<u8, ConstU32<256>>,
+            target: BoundedVec<u8, ConstU32<256>>,
+        },
+        UserUnblocked {
+            name: BoundedVec<u8, ConstU32<256>>,
+            target: BoundedVec<u8, ConstU32<256>>,
+        },
+        UserMuted {
+            name: BoundedVec<u8, ConstU32<256>>,
+            target: BoundedVec<u8, ConstU32<256>>,
+        },
+        UserUnmuted {
+            name: BoundedVec<u8, ConstU32<256>>,
+            target: BoundedVec<u8, ConstU32<256>>,
+        },
+        TweetReported {
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+            reporter: T::AccountId,
+        },
+        ReportsDismissed {
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        },
+        TweetHidden {
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        },
+        TweetRemoved {
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        },
+        NameSuspended {
+            name: BoundedVec<u8, ConstU32<256>>,
+        },
+        AppealFiled {
+            name: BoundedVec<u8, ConstU32<256>>,
+            subject: AppealSubject,
+        },
+        AppealResolved {
+            name: BoundedVec<u8, ConstU32<256>>,
+            subject: AppealSubject,
+            reinstated: bool,
+        },
+        UserSuspended {
+            name: BoundedVec<u8, ConstU32<256>>,
+            until: BlockNumberFor<T>,
+        },
+        UserUnsuspended {
+            name: BoundedVec<u8, ConstU32<256>>,
+        },
+        UserBanned {
+            name: BoundedVec<u8, ConstU32<256>>,
+        },
+        RegistrarAdded {
+            registrar: T::AccountId,
+        },
+        RegistrarRemoved {
+            registrar: T::AccountId,
+        },
+        JudgementGiven {
+            name: BoundedVec<u8, ConstU32<256>>,
+            registrar: T::AccountId,
+            judgement: Judgement,
+        },
+        JudgementRevoked {
+            name: BoundedVec<u8, ConstU32<256>>,
+            registrar: T::AccountId,
+        },
+        MessagingKeyRegistered {
+            name: BoundedVec<u8, ConstU32<256>>,
+        },
+        MessageSent {
+            from: BoundedVec<u8, ConstU32<256>>,
+            to: BoundedVec<u8, ConstU32<256>>,
+            message_id: u32,
+        },
+        MessageDeleted {
+            by: T::AccountId,
+            with: T::AccountId,
+            message_id: u32,
+        },
+        PollCreated {
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+            ends_at: BlockNumberFor<T>,
+        },
+        PollVoted {
+            name: BoundedVec<u8, ConstU32<256>>,
+            author: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+            option: u8,
+        },
+        /// A poll reached its end block, with the final votes per option.
+        PollClosed {
+            author: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+            tallies: BoundedVec<u32, ConstU32<4>>,
+        },
+        TweetScheduled {
+            name: BoundedVec<u8, ConstU32<256>>,
+            schedule_id: u32,
+            at: BlockNumberFor<T>,
+        },
+        ScheduledTweetCancelled {
+            name: BoundedVec<u8, ConstU32<256>>,
+            schedule_id: u32,
+        },
+        TweetExpired {
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        },
+        TweetPinned {
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        },
+        TweetUnpinned {
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        },
+        TweetBookmarked {
+            who: T::AccountId,
+            author: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        },
+        BookmarkRemoved {
+            who: T::AccountId,
+            author: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        },
+        ListCreated {
+            name: BoundedVec<u8, ConstU32<256>>,
+            list: BoundedVec<u8, ConstU32<64>>,
+            private: bool,
+        },
+        AddedToList {
+            name: BoundedVec<u8, ConstU32<256>>,
+            list: BoundedVec<u8, ConstU32<64>>,
+            member: BoundedVec<u8, ConstU32<256>>,
+        },
+        RemovedFromList {
+            name: BoundedVec<u8, ConstU32<256>>,
+            list: BoundedVec<u8, ConstU32<64>>,
+            member: BoundedVec<u8, ConstU32<256>>,
+        },
+        ListDeleted {
+            name: BoundedVec<u8, ConstU32<256>>,
+            list: BoundedVec<u8, ConstU32<64>>,
+        },
+        Tipped {
+            who: T::AccountId,
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+            amount: BalanceOf<T>,
+        },
+        TippedWithAsset {
+            who: T::AccountId,
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+            asset_id: AssetIdOf<T>,
+            amount: AssetBalanceOf<T>,
+        },
+        AvatarSet {
+            name: BoundedVec<u8, ConstU32<256>>,
+            collection: T::NftCollectionId,
+            item: T::NftId,
+        },
+        AvatarCleared {
+            name: BoundedVec<u8, ConstU32<256>>,
+        },
+        DeviceAdded {
+            name: BoundedVec<u8, ConstU32<256>>,
+            device: T::AccountId,
+            scopes: BoundedVec<DeviceScope, ConstU32<3>>,
+            expires_at: Option<BlockNumberFor<T>>,
+        },
+        DeviceRemoved {
+            name: BoundedVec<u8, ConstU32<256>>,
+            device: T::AccountId,
+        },
+        RecoverySet {
+            name: BoundedVec<u8, ConstU32<256>>,
+            friends: BoundedVec<T::AccountId, T::MaxFriends>,
+            threshold: u32,
+            delay: BlockNumberFor<T>,
+        },
+        RecoveryRemoved {
+            name: BoundedVec<u8, ConstU32<256>>,
+        },
+        RecoveryInitiated {
+            name: BoundedVec<u8, ConstU32<256>>,
+            rescuer: T::AccountId,
+        },
+        RecoveryVouched {
+            name: BoundedVec<u8, ConstU32<256>>,
+            rescuer: T::AccountId,
+            friend: T::AccountId,
+        },
+        RecoveryCancelled {
+            name: BoundedVec<u8, ConstU32<256>>,
+            rescuer: T::AccountId,
+        },
+        /// The name was rebound from the lost account `from` to the account `to` that
+        /// recovered it.
+        NameRecovered {
+            name: BoundedVec<u8, ConstU32<256>>,
+            from: T::AccountId,
+            to: T::AccountId,
+        },
+        NameTransferred {
+            name: BoundedVec<u8, ConstU32<256>>,
+            from: T::AccountId,
+            to: T::AccountId,
+        },
+        NameListed {
+            name: BoundedVec<u8, ConstU32<256>>,
+            price: BalanceOf<T>,
+        },
+        NameUnlisted {
+            name: BoundedVec<u8, ConstU32<256>>,
+        },
+        NameSold {
+            name: BoundedVec<u8, ConstU32<256>>,
+            from: T::AccountId,
+            to: T::AccountId,
+            price: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NoneValue,
+        StorageOverflow,
+        UserNotFound,
+        InvalidCredentials,
+        NameAlreadyTaken,
+        TweetTooLong,
+        NotAuthorized,
+        InvalidBirthday,
+        InvalidCaller,
+        TweetNotFound,
+        TooManyTweets,
+        /// The user addressed by the call has blocked the caller.
+        Blocked,
+        NotBlocked,
+        NotMuted,
+        /// Users can't block or mute themselves.
+        CannotTargetSelf,
+        TooManyMentions,
+        /// The caller already reported this tweet.
+        AlreadyReported,
+        /// The tweet has as many open reports as `MaxReportsPerTweet` allows.
+        TooManyReports,
+        NoReports,
+        /// The name is suspended.
+        Suspended,
+        AlreadySuspended,
+        /// There is no moderation decision of that kind to appeal against.
+        NothingToAppeal,
+        AppealPending,
+        AppealNotFound,
+        /// The account is banned.
+        Banned,
+        NotSuspended,
+        /// Suspensions must end after the current block.
+        SuspensionInPast,
+        NotRegistrar,
+        AlreadyRegistrar,
+        TooManyRegistrars,
+        NoJudgement,
+        /// Registering a name requires a `Reasonable` or `KnownGood` identity judgement.
+        IdentityRequired,
+        /// The recipient has not registered a messaging key.
+        NoMessagingKey,
+        /// The conversation holds `MaxMessagesPerConversation` messages already.
+        ConversationFull,
+        MessageNotFound,
+        /// Polls need two to four non-empty options.
+        InvalidPollOptions,
+        /// Polls must end after the current block and within `MaxPollDuration` blocks.
+        InvalidPollEnd,
+        /// `MaxPollsEndingPerBlock` polls end at that block already.
+        TooManyPollsEnding,
+        PollNotFound,
+        PollClosed,
+        AlreadyVoted,
+        /// The poll has no option with that index.
+        InvalidPollOption,
+        /// Tweets can only be scheduled for a later block.
+        ScheduleInPast,
+        /// The user has `MaxScheduledTweets` tweets scheduled already.
+        TooManyScheduledTweets,
+        /// The user has `MaxScheduledTweetsPerBlock` tweets scheduled for that block already.
+        TooManyScheduledForBlock,
+        ScheduledTweetNotFound,
+        /// Tweets can only expire after the current block.
+        ExpiryInPast,
+        /// `MaxExpiringPerBlock` tweets expire at that block already.
+        TooManyTweetsExpiring,
+        AlreadyPinned,
+        /// The user has `MaxPinnedTweets` tweets pinned already.
+        TooManyPins,
+        NotPinned,
+        AlreadyBookmarked,
+        /// The account has `MaxBookmarks` bookmarks already.
+        TooManyBookmarks,
+        NotBookmarked,
+        ListAlreadyExists,
+        /// The user has `MaxListsPerUser` lists already.
+        TooManyLists,
+        ListNotFound,
+        AlreadyInList,
+        /// The list has `MaxListMembers` members already.
+        ListFull,
+        NotInList,
+        ZeroTip,
+        /// The tweet has been tipped in `MaxTipAssets` other assets already.
+        TooManyTipAssets,
+        /// The caller doesn't own the NFT.
+        NotNftOwner,
+        NoAvatar,
+        /// Only the user can clear their avatar while they still own the NFT.
+        AvatarStillOwned,
+        /// The account has `MaxDevices` device keys already.
+        TooManyDevices,
+        DeviceNotFound,
+        /// The device key lacks the scope the call needs.
+        MissingDeviceScope,
+        DeviceExpired,
+        /// Recovery thresholds must be between one and the number of friends.
+        InvalidRecoveryThreshold,
+        DuplicateFriend,
+        /// The name has no recovery config.
+        NotRecoverable,
+        /// A friend vouched for recovering the name, so its owner can't change or give it up
+        /// until the recovery is claimed or cancelled.
+        RecoveryInProgress,
+        RecoveryAlreadyStarted,
+        RecoveryNotFound,
+        /// The caller isn't a friend in the name's recovery config.
+        NotFriend,
+        AlreadyVouched,
+        /// Fewer friends than the recovery threshold vouched so far.
+        ThresholdNotReached,
+        /// The recovery delay hasn't passed yet.
+        RecoveryDelayNotPassed,
+        /// Names can only go to accounts without a name.
+        AccountHasName,
+        NotForSale,
+        /// The name is listed at a different price than the buyer offered.
+        SalePriceChanged,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let ending = PollsEndingAt::<T>::take(now);
+            for (author, tweet_id) in &ending {
+                let Some(tallies) = Polls::<T>::mutate(author, tweet_id, |poll| {
+                    poll.as_mut().map(|poll| {
+                        poll.closed = true;
+                        poll.tallies.clone()
+                    })
+                }) else {
+                    continue;
+                };
+                Self::deposit_event(Event::PollClosed {
+                    author: Names::<T>::get(author).unwrap_or_default(),
+                    tweet_id: *tweet_id,
+                    tallies,
+                });
+            }
+            <T as pallet::Config>::WeightInfo::close_polls(ending.len() as u32)
+        }
+
+        /// Remove expired tweets, the bookmarks of deleted tweets and avatars whose NFT changed
+        /// hands, as far as `remaining_weight` allows.
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(remaining_weight);
+            Self::remove_expired_tweets(now, &mut meter);
+            Self::remove_stale_bookmarks(&mut meter);
+            Self::remove_stale_avatars(&mut meter);
+            meter.consumed()
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_user(
+            name.len() as u32,
+            password.len() as u32,
+        )
+        // Looking up the caller's identity
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn create_user(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            year: u16,
+            month: u8,
+            day: u8,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_suspended(&who)?;
+            if T::RequireIdentity::get() {
+                ensure!(
+                    matches!(
+                        T::Identity::judgement(&who),
+                        Some(Judgement::Reasonable | Judgement::KnownGood)
+                    ),
+                    Error::<T>::IdentityRequired
+                );
+            }
+
+            // Validate birthday
+            ensure!(month <= 12 && month > 0, Error::<T>::InvalidBirthday);
+            ensure!(day <= 31 && day > 0, Error::<T>::InvalidBirthday);
+            ensure!((1920..=2006).contains(&year), Error::<T>::InvalidBirthday);
+
+            // Ensure name isn't already taken
+            ensure!(!AccountByName::<T>::contains_key(&name), Error::<T>::NameAlreadyTaken);
+
+            // Store name mappings
+            Names::<T>::insert(&who, name.clone());
+            AccountByName::<T>::insert(&name, who.clone());
+
+            // Store birthday
+            let birthday = Birthday { year, month, day };
+            Birthdays::<T>::insert(&name, 0, birthday);
+
+            // Hash and store password
+            let password_hash = blake2_256(&password[..]);
+            PasswordHash::<T>::insert(&name, 0, password_hash);
+
+            // Initialize tweet count
+            TweetCount::<T>::insert(&name, 0);
+
+            // Emit events
+            Self::deposit_event(Event::NameStored { name: name.clone(), who: who.clone() });
+            Self::deposit_event(Event::BirthdayStored { name: name.clone(), year, month, day });
+            Self::deposit_event(Event::PasswordHashed { name: name.clone() });
+
+            Ok(())
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::verify_user(
+            name.len() as u32,
+            password.len() as u32,
+        ))]
+        pub fn verify_user(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>
+        ) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+
+            // Get account by name
+            let account = AccountByName::<T>::get(&name)
+                .ok_or(Error::<T>::UserNotFound)?;
+
+            // Get stored password hash
+            let stored_hash = PasswordHash::<T>::get(&name,0)
+                .ok_or(Error::<T>::UserNotFound)?;
+
+            // Hash the provided password
+            let password_hash = blake2_256(&password[..]);
+
+            // Compare hashes using constant-time comparison
+            if password_hash == stored_hash {
+                Self::deposit_event(Event::LoginSuccessful { name, who: account });
+                Ok(())
+            } else {
+                Self::deposit_event(Event::LoginFailed { name });
+                Err(Error::<T>::InvalidCredentials.into())
+            }
+        }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_tweet(
+            name.len() as u32,
+            password.len() as u32,
+            content.len() as u32,
+        )
+        // Looking up the mentioned users and whether they blocked the author
+        .saturating_add(T::DbWeight::get().reads(2 * T::MaxMentions::get() as u64))
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn create_tweet(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            content: BoundedVec<u8, ConstU32<280>>
+        ) -> DispatchResult {
+            let who =
+                Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Post)?;
+
+            Self::post_tweet(&who, name, content, None)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::get_user_birthday(name.len() as u32))]
+        pub fn get_user_birthday(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            ensure!(AccountByName::<T>::contains_key(&name), Error::<T>::UserNotFound);
+
+            let birthday = Birthdays::<T>::get(&name, 0)
+                .ok_or(Error::<T>::UserNotFound)?;
+
+            log::info!("User {:?} has birthday: {:?}", name, birthday);
+            Ok(())
+        }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::get_user_tweets(
+            name.len() as u32,
+            T::MaxTweetsPerUser::get(),
+        ))]
+        pub fn get_user_tweets(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let account = AccountByName::<T>::get(&name).ok_or(Error::<T>::UserNotFound)?;
+
+            let tweet_count = TweetCount::<T>::get(&name);
+            for tweet_id in 0..tweet_count {
+                if HiddenTweets::<T>::contains_key(&account, tweet_id) {
+                    continue;
+                }
+                if let Some(tweet) = Tweets::<T>::get(&name, tweet_id) {
+                    log::info!("Tweet ID {}: {:?}", tweet_id, tweet);
+                }
+            }
+
+            // Only charge for the tweets we actually read
+            Ok(Some(<T as pallet::Config>::WeightInfo::get_user_tweets(
+                name.len() as u32,
+                tweet_count,
+            ))
+            .into())
+        }
+
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::update_user_name(
+            name.len().max(new_name.len()) as u32,
+            password.len() as u32,
+            T::MaxTweetsPerUser::get(),
+        ))]
+        pub fn update_user_name(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            new_name: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let account = AccountByName::<T>::get(&name)
+                .ok_or(Error::<T>::UserNotFound)?;
+            ensure!(account == who, Error::<T>::NotAuthorized);
+
+            let stored_hash = PasswordHash::<T>::get(&name, 0)
+                .ok_or(Error::<T>::UserNotFound)?;
+
+            let password_hash = blake2_256(&password[..]);
+            ensure!(password_hash == stored_hash, Error::<T>::InvalidCredentials);
+            Self::ensure_not_suspended(&who)?;
+            Self::ensure_no_active_recovery(&name)?;
+
+            // Ensure new name isn't taken
+            ensure!(!AccountByName::<T>::contains_key(&new_name), Error::<T>::NameAlreadyTaken);
+
+            // Remove old name mapping
+            Names::<T>::remove(&who);
+            PasswordHash::<T>::remove(&name, 0);
+            AccountByName::<T>::remove(&name);
+
+            // Store new name
+            Names::<T>::insert(&who, new_name.clone());
+            AccountByName::<T>::insert(&new_name, who.clone());
+            PasswordHash::<T>::insert(&new_name, 0, password_hash);
+            if let Some(birthday) = Birthdays::<T>::take(&name, 0) {
+                Birthdays::<T>::insert(&new_name, 0, birthday);
+            }
+
+            // Move all tweets to new name
+            let tweet_count = TweetCount::<T>::get(&name);
+            for tweet_id in 0..tweet_count {
+                if let Some(mut tweet) = Tweets::<T>::take(&name, tweet_id) {
+                    tweet.name = new_name.clone();
+                    Tweets::<T>::insert(&new_name, tweet_id, tweet);
+                }
+            }
+
+            // Update tweet count
+            TweetCount::<T>::remove(&name);
+            TweetCount::<T>::insert(&new_name, tweet_count);
+
+            // Move all lists to new name
+            for (list, user_list) in Lists::<T>::drain_prefix(&name) {
+                Lists::<T>::insert(&new_name, list, user_list);
+            }
+            ListCount::<T>::insert(&new_name, ListCount::<T>::take(&name));
+
+            // Registrars judged the old name
+            Judgements::<T>::remove(&name);
+            if let Some(config) = Recoveries::<T>::take(&name) {
+                Recoveries::<T>::insert(&new_name, config);
+            }
+            NamesForSale::<T>::remove(&name);
+
+            Self::deposit_event(Event::NameStored { name: new_name.clone(), who });
+            Self::deposit_event(Event::PasswordHashed { name: new_name.clone() });
+
+            // Only charge for the tweets we actually moved
+            Ok(Some(<T as pallet::Config>::WeightInfo::update_user_name(
+                name.len().max(new_name.len()) as u32,
+                password.len() as u32,
+                tweet_count,
+            ))
+            .into())
+        }
+
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::update_password(
+            name.len() as u32,
+            old_password.len().max(new_password.len()) as u32,
+        ))]
+        pub fn update_password(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            old_password: BoundedVec<u8, ConstU32<256>>,
+            new_password: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let account = AccountByName::<T>::get(&name)
+                .ok_or(Error::<T>::UserNotFound)?;
+            ensure!(account == who, Error::<T>::NotAuthorized);
+
+            let stored_hash = PasswordHash::<T>::get(&name, 0)
+                .ok_or(Error::<T>::UserNotFound)?;
+
+            let old_hash = blake2_256(&old_password[..]);
+            ensure!(old_hash == stored_hash, Error::<T>::InvalidCredentials);
+
+            let new_hash = blake2_256(&new_password[..]);
+            PasswordHash::<T>::insert(&name, 0, new_hash);
+
+            Self::deposit_event(Event::PasswordHashed { name });
+
+            Ok(())
+        }
+
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::delete_tweet(
+            name.len() as u32,
+            password.len() as u32,
+        )
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn delete_tweet(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        ) -> DispatchResult {
+            let who =
+                Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Delete)?;
+
+            // Ensure tweet exists
+            ensure!(Tweets::<T>::contains_key(&name, tweet_id), Error::<T>::TweetNotFound);
+
+            // Remove the tweet and anything moderators had to say about it
+            Tweets::<T>::remove(&name, tweet_id);
+            Self::forget_tweet(&who, tweet_id);
+
+            Ok(())
+
+        }
+
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::delete_user(
+            name.len() as u32,
+            password.len() as u32,
+            T::MaxTweetsPerUser::get(),
+        ))]
+        pub fn delete_user(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // Get account by name
+            let account = AccountByName::<T>::get(&name)
+                .ok_or(Error::<T>::UserNotFound)?;
+            
+            // Ensure the caller owns this account
+            ensure!(account == who, Error::<T>::NotAuthorized);
+
+            // Verify password
+            let stored_hash = PasswordHash::<T>::get(&name, 0)
+                .ok_or(Error::<T>::UserNotFound)?;
+            let password_hash = blake2_256(&password[..]);
+            ensure!(password_hash == stored_hash, Error::<T>::InvalidCredentials);
+            Self::ensure_no_active_recovery(&name)?;
+
+            // Remove name mappings
+            Names::<T>::remove(&account);
+            AccountByName::<T>::remove(&name);
+
+            // Remove birthday and password
+            Birthdays::<T>::remove(&name, 0);
+            PasswordHash::<T>::remove(&name, 0);
+
+            // Remove all tweets
+            let tweet_count = TweetCount::<T>::get(&name);
+            for tweet_id in 0..tweet_count {
+                Tweets::<T>::remove(&name, tweet_id);
+                Self::forget_tweet(&account, tweet_id);
+            }
+            TweetCount::<T>::remove(&name);
+            let _ = Lists::<T>::clear_prefix(&name, T::MaxListsPerUser::get(), None);
+            ListCount::<T>::remove(&name);
+            Judgements::<T>::remove(&name);
+            Recoveries::<T>::remove(&name);
+            NamesForSale::<T>::remove(&name);
+            Avatars::<T>::remove(&account);
+            let _ = Devices::<T>::clear_prefix(&account, T::MaxDevices::get(), None);
+            DeviceCount::<T>::remove(&account);
+            Self::cancel_scheduled_tweets(&account);
+
+            // Give back the deposits of the messages the user sent. The messages stay until a
+            // participant deletes them, which then releases nothing.
+            T::Currency::release_all(
+                &HoldReason::MessageDeposit.into(),
+                &account,
+                Precision::BestEffort,
+            )?;
+            MessageDepositRound::<T>::mutate(&account, |round| round.saturating_inc());
+
+            // Only charge for the tweets we actually removed
+            Ok(Some(<T as pallet::Config>::WeightInfo::delete_user(
+                name.len() as u32,
+                password.len() as u32,
+                tweet_count,
+            ))
+            .into())
+        }
+
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::block_user(
+            name.len().max(target.len()) as u32,
+            password.len() as u32,
+        ))]
+        pub fn block_user(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            target: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+
+            let blocked = AccountByName::<T>::get(&target).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(blocked != who, Error::<T>::CannotTargetSelf);
+            Blocked::<T>::insert(&who, &blocked, ());
+
+            Self::deposit_event(Event::UserBlocked { name, target });
+            Ok(())
+        }
+
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unblock_user(
+            name.len().max(target.len()) as u32,
+            password.len() as u32,
+        ))]
+        pub fn unblock_user(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            target: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+
+            let blocked = AccountByName::<T>::get(&target).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(Blocked::<T>::contains_key(&who, &blocked), Error::<T>::NotBlocked);
+            Blocked::<T>::remove(&who, &blocked);
+
+            Self::deposit_event(Event::UserUnblocked { name, target });
+            Ok(())
+        }
+
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::mute_user(
+            name.len().max(target.len()) as u32,
+            password.len() as u32,
+        ))]
+        pub fn mute_user(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            target: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+
+            let muted = AccountByName::<T>::get(&target).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(muted != who, Error::<T>::CannotTargetSelf);
+            Muted::<T>::insert(&who, &muted, ());
+
+            Self::deposit_event(Event::UserMuted { name, target });
+            Ok(())
+        }
+
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unmute_user(
+            name.len().max(target.len()) as u32,
+            password.len() as u32,
+        ))]
+        pub fn unmute_user(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            target: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+
+            let muted = AccountByName::<T>::get(&target).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(Muted::<T>::contains_key(&who, &muted), Error::<T>::NotMuted);
+            Muted::<T>::remove(&who, &muted);
+
+            Self::deposit_event(Event::UserUnmuted { name, target });
+            Ok(())
+        }
+
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::report_tweet(
+            name.len() as u32,
+            T::MaxReportsPerTweet::get(),
+        ))]
+        pub fn report_tweet(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+            reason: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+
+            let author = Self::tweet_author(&name, tweet_id)?;
+            ensure!(author != reporter, Error::<T>::CannotTargetSelf);
+
+            Reports::<T>::try_mutate(&author, tweet_id, |reports| -> DispatchResult {
+                let reports = reports.get_or_insert_with(Default::default);
+                ensure!(
+                    !reports.iter().any(|report| report.reporter == reporter),
+                    Error::<T>::AlreadyReported
+                );
+                reports
+                    .try_push(Report { reporter: reporter.clone(), reason })
+                    .map_err(|_| Error::<T>::TooManyReports)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::TweetReported { name, tweet_id, reporter });
+            Ok(())
+        }
+
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::dismiss_reports(name.len() as u32))]
+        pub fn dismiss_reports(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        ) -> DispatchResult {
+            T::ModeratorOrigin::ensure_origin(origin)?;
+
+            let author = AccountByName::<T>::get(&name).ok_or(Error::<T>::UserNotFound)?;
+            Reports::<T>::take(&author, tweet_id).ok_or(Error::<T>::NoReports)?;
+
+            Self::deposit_event(Event::ReportsDismissed { name, tweet_id });
+            Ok(())
+        }
+
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::hide_tweet(name.len() as u32))]
+        pub fn hide_tweet(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        ) -> DispatchResult {
+            T::ModeratorOrigin::ensure_origin(origin)?;
+
+            let author = Self::tweet_author(&name, tweet_id)?;
+            HiddenTweets::<T>::insert(&author, tweet_id, ());
+            Reports::<T>::remove(&author, tweet_id);
+
+            Self::deposit_event(Event::TweetHidden { name, tweet_id });
+            Ok(())
+        }
+
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_tweet(name.len() as u32))]
+        pub fn remove_tweet(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        ) -> DispatchResult {
+            T::ModeratorOrigin::ensure_origin(origin)?;
+
+            let author = Self::tweet_author(&name, tweet_id)?;
+            Tweets::<T>::remove(&name, tweet_id);
+            Self::forget_tweet(&author, tweet_id);
+
+            Self::deposit_event(Event::TweetRemoved { name, tweet_id });
+            Ok(())
+        }
+
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::suspend_name(name.len() as u32))]
+        pub fn suspend_name(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            T::ModeratorOrigin::ensure_origin(origin)?;
+
+            let account = AccountByName::<T>::get(&name).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(Self::active_suspension(&account).is_none(), Error::<T>::AlreadySuspended);
+            Suspended::<T>::insert(&account, Suspension::Indefinite);
+
+            Self::deposit_event(Event::NameSuspended { name });
+            Ok(())
+        }
+
+        /// Ask moderators to reconsider hiding one of the caller's tweets or suspending its name.
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::appeal(
+            name.len() as u32,
+            password.len() as u32,
+        ))]
+        pub fn appeal(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            subject: AppealSubject,
+            statement: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+
+            let appealable = match subject {
+                AppealSubject::Tweet(tweet_id) => HiddenTweets::<T>::contains_key(&who, tweet_id),
+                AppealSubject::Suspension => matches!(
+                    Self::active_suspension(&who),
+                    Some(Suspension::Indefinite | Suspension::Until(_))
+                ),
+            };
+            ensure!(appealable, Error::<T>::NothingToAppeal);
+            ensure!(!Appeals::<T>::contains_key(&who, subject), Error::<T>::AppealPending);
+            Appeals::<T>::insert(&who, subject, statement);
+
+            Self::deposit_event(Event::AppealFiled { name, subject });
+            Ok(())
+        }
+
+        /// Rule on a pending appeal. With `reinstate` the hidden tweet is shown again or the
+        /// suspension lifted, otherwise the decision stands.
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::resolve_appeal(name.len() as u32))]
+        pub fn resolve_appeal(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            subject: AppealSubject,
+            reinstate: bool,
+        ) -> DispatchResult {
+            T::ModeratorOrigin::ensure_origin(origin)?;
+
+            let account = AccountByName::<T>::get(&name).ok_or(Error::<T>::UserNotFound)?;
+            Appeals::<T>::take(&account, subject).ok_or(Error::<T>::AppealNotFound)?;
+            if reinstate {
+                match subject {
+                    AppealSubject::Tweet(tweet_id) => HiddenTweets::<T>::remove(&account, tweet_id),
+                    // An admin may have banned the user while the appeal was pending
+                    AppealSubject::Suspension => Suspended::<T>::mutate_exists(&account, |suspension| {
+                        if *suspension != Some(Suspension::Banned) {
+                            *suspension = None;
+                        }
+                    }),
+                }
+            }
+
+            Self::deposit_event(Event::AppealResolved { name, subject, reinstated: reinstate });
+            Ok(())
+        }
+
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::suspend_user(name.len() as u32))]
+        pub fn suspend_user(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            until_block: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let account = AccountByName::<T>::get(&name).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(
+                until_block > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::SuspensionInPast
+            );
+            ensure!(Suspended::<T>::get(&account) != Some(Suspension::Banned), Error::<T>::Banned);
+            Suspended::<T>::insert(&account, Suspension::Until(until_block));
+
+            Self::deposit_event(Event::UserSuspended { name, until: until_block });
+            Ok(())
+        }
+
+        /// Lift any suspension or ban of `name`, along with a pending appeal against it.
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unsuspend_user(name.len() as u32))]
+        pub fn unsuspend_user(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let account = AccountByName::<T>::get(&name).ok_or(Error::<T>::UserNotFound)?;
+            Suspended::<T>::take(&account).ok_or(Error::<T>::NotSuspended)?;
+            Appeals::<T>::remove(&account, AppealSubject::Suspension);
+
+            Self::deposit_event(Event::UserUnsuspended { name });
+            Ok(())
+        }
+
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::ban_user(name.len() as u32))]
+        pub fn ban_user(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let account = AccountByName::<T>::get(&name).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(Suspended::<T>::get(&account) != Some(Suspension::Banned), Error::<T>::Banned);
+            Suspended::<T>::insert(&account, Suspension::Banned);
+            Appeals::<T>::remove(&account, AppealSubject::Suspension);
+
+            Self::deposit_event(Event::UserBanned { name });
+            Ok(())
+        }
+
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::add_registrar())]
+        pub fn add_registrar(origin: OriginFor<T>, registrar: T::AccountId) -> DispatchResult {
+            ensure_root(origin)?;
+
+            Registrars::<T>::try_mutate(|registrars| -> DispatchResult {
+                ensure!(!registrars.contains(&registrar), Error::<T>::AlreadyRegistrar);
+                registrars
+                    .try_push(registrar.clone())
+                    .map_err(|_| Error::<T>::TooManyRegistrars)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::RegistrarAdded { registrar });
+            Ok(())
+        }
+
+        /// Remove a registrar. Its judgements stop showing up in profiles right away.
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_registrar())]
+        pub fn remove_registrar(origin: OriginFor<T>, registrar: T::AccountId) -> DispatchResult {
+            ensure_root(origin)?;
+
+            Registrars::<T>::try_mutate(|registrars| -> DispatchResult {
+                let index = registrars
+                    .iter()
+                    .position(|known| known == &registrar)
+                    .ok_or(Error::<T>::NotRegistrar)?;
+                registrars.remove(index);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::RegistrarRemoved { registrar });
+            Ok(())
+        }
+
+        /// Give `name` a judgement, replacing the one the caller gave before if any.
+        #[pallet::call_index(25)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::provide_judgement(name.len() as u32))]
+        pub fn provide_judgement(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            judgement: Judgement,
+        ) -> DispatchResult {
+            let registrar = ensure_signed(origin)?;
+
+            let registrars = Registrars::<T>::get();
+            ensure!(registrars.contains(&registrar), Error::<T>::NotRegistrar);
+            ensure!(AccountByName::<T>::contains_key(&name), Error::<T>::UserNotFound);
+
+            Judgements::<T>::try_mutate(&name, |judgements| -> DispatchResult {
+                // Make room by dropping judgements of removed registrars
+                judgements.retain(|(by, _)| by != &registrar && registrars.contains(by));
+                judgements
+                    .try_push((registrar.clone(), judgement))
+                    .map_err(|_| Error::<T>::TooManyRegistrars)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::JudgementGiven { name, registrar, judgement });
+            Ok(())
+        }
+
+        #[pallet::call_index(26)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_judgement(name.len() as u32))]
+        pub fn revoke_judgement(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let registrar = ensure_signed(origin)?;
+
+            Judgements::<T>::try_mutate_exists(&name, |judgements| -> DispatchResult {
+                let list = judgements.as_mut().ok_or(Error::<T>::NoJudgement)?;
+                let index = list
+                    .iter()
+                    .position(|(by, _)| by == &registrar)
+                    .ok_or(Error::<T>::NoJudgement)?;
+                list.remove(index);
+                if list.is_empty() {
+                    *judgements = None;
+                }
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::JudgementRevoked { name, registrar });
+            Ok(())
+        }
+
+        /// Set the X25519 public key others encrypt direct messages to the caller with.
+        #[pallet::call_index(27)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::register_messaging_key())]
+        pub fn register_messaging_key(origin: OriginFor<T>, key: [u8; 32]) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let name = Names::<T>::get(&who).ok_or(Error::<T>::UserNotFound)?;
+
+            MessagingKeys::<T>::insert(&who, key);
+
+            Self::deposit_event(Event::MessagingKeyRegistered { name });
+            Ok(())
+        }
+
+        /// Store a message encrypted to the messaging key of `to_name`, holding
+        /// `MessageDeposit` from the caller until the message is deleted.
+        #[pallet::call_index(28)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::send_message(
+            to_name.len() as u32,
+            ciphertext.len() as u32,
+        ))]
+        pub fn send_message(
+            origin: OriginFor<T>,
+            to_name: BoundedVec<u8, ConstU32<256>>,
+            ciphertext: BoundedVec<u8, ConstU32<1024>>,
+            nonce: [u8; 24],
+            ephemeral_pubkey: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let from = Names::<T>::get(&who).ok_or(Error::<T>::UserNotFound)?;
+            Self::ensure_not_suspended(&who)?;
+
+            let to = AccountByName::<T>::get(&to_name).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(to != who, Error::<T>::CannotTargetSelf);
+            Self::ensure_not_blocked(&who, &to)?;
+            ensure!(MessagingKeys::<T>::contains_key(&to), Error::<T>::NoMessagingKey);
+
+            let conversation = Self::conversation(&who, &to);
+            let (message_id, stored) = MessageCount::<T>::get(&conversation);
+            ensure!(stored < T::MaxMessagesPerConversation::get(), Error::<T>::ConversationFull);
+
+            let deposit = T::MessageDeposit::get();
+            T::Currency::hold(&HoldReason::MessageDeposit.into(), &who, deposit)?;
+            let deposit_round = MessageDepositRound::<T>::get(&who);
+
+            let message = Message {
+                from: who,
+                ciphertext,
+                nonce,
+                ephemeral_pubkey,
+                sent_at: frame_system::Pallet::<T>::block_number(),
+                deposit,
+                deposit_round,
+            };
+            Messages::<T>::insert(&conversation, message_id, message);
+            MessageCount::<T>::insert(
+                &conversation,
+                (message_id.saturating_add(1), stored.saturating_add(1)),
+            );
+
+            Self::deposit_event(Event::MessageSent { from, to: to_name, message_id });
+            Ok(())
+        }
+
+        /// Delete a message from the caller's conversation with account `with`. Either
+        /// participant can delete any message, and the deposit goes back to the sender unless
+        /// `delete_user` released it already. Conversations are addressed by account, so they can
+        /// be cleaned up after either side deleted, renamed or handed over their name.
+        #[pallet::call_index(29)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::delete_message())]
+        pub fn delete_message(
+            origin: OriginFor<T>,
+            with: T::AccountId,
+            message_id: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let conversation = Self::conversation(&who, &with);
+            let message =
+                Messages::<T>::take(&conversation, message_id).ok_or(Error::<T>::MessageNotFound)?;
+            MessageCount::<T>::mutate(&conversation, |(_, stored)| {
+                *stored = stored.saturating_sub(1)
+            });
+            if message.deposit_round == MessageDepositRound::<T>::get(&message.from) {
+                T::Currency::release(
+                    &HoldReason::MessageDeposit.into(),
+                    &message.from,
+                    message.deposit,
+                    Precision::BestEffort,
+                )?;
+            }
+
+            Self::deposit_event(Event::MessageDeleted { by: who, with, message_id });
+            Ok(())
+        }
+
+        /// Queue `content` to be posted as `name` at block `at`. The scheduler dispatches
+        /// `publish_scheduled_tweet` from the caller's account when it is due.
+        #[pallet::call_index(32)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::schedule_tweet(
+            name.len() as u32,
+            password.len() as u32,
+            content.len() as u32,
+        )
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn schedule_tweet(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            content: BoundedVec<u8, ConstU32<280>>,
+            at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who =
+                Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Post)?;
+            Self::ensure_not_suspended(&who)?;
+            ensure!(at > frame_system::Pallet::<T>::block_number(), Error::<T>::ScheduleInPast);
+
+            let schedule_id = NextScheduleId::<T>::get(&who);
+            ScheduledTweets::<T>::try_mutate(&who, |scheduled| {
+                let same_block = scheduled.iter().filter(|tweet| tweet.at == at).count() as u32;
+                ensure!(
+                    same_block < T::MaxScheduledTweetsPerBlock::get(),
+                    Error::<T>::TooManyScheduledForBlock
+                );
+                scheduled
+                    .try_push(ScheduledTweet { id: schedule_id, content, at })
+                    .map_err(|_| Error::<T>::TooManyScheduledTweets)
+            })?;
+            NextScheduleId::<T>::insert(&who, schedule_id.saturating_add(1));
+
+            let call: <T as Config>::RuntimeCall =
+                Call::<T>::publish_scheduled_tweet { schedule_id }.into();
+            let call = BoundedVec::try_from(call.encode()).map_err(|_| Error::<T>::StorageOverflow)?;
+            T::Scheduler::schedule_named(
+                Self::schedule_task(&who, schedule_id),
+                DispatchTime::At(at),
+                None,
+                schedule::LOWEST_PRIORITY,
+                frame_system::RawOrigin::Signed(who).into(),
+                Bounded::Inline(call),
+            )?;
+
+            Self::deposit_event(Event::TweetScheduled { name, schedule_id, at });
+            Ok(())
+        }
+
+        /// Drop a tweet queued with `schedule_tweet` before it is published.
+        #[pallet::call_index(33)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_scheduled_tweet(
+            name.len() as u32,
+            password.len() as u32,
+        )
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn cancel_scheduled_tweet(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            schedule_id: u32,
+        ) -> DispatchResult {
+            let who =
+                Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Delete)?;
+            Self::take_scheduled_tweet(&who, schedule_id)?;
+
+            // The task is already gone if publishing it failed
+            let _ = T::Scheduler::cancel_named(Self::schedule_task(&who, schedule_id));
+
+            Self::deposit_event(Event::ScheduledTweetCancelled { name, schedule_id });
+            Ok(())
+        }
+
+        /// Post the tweet the caller scheduled as `schedule_id`. Dispatched by the scheduler,
+        /// calling it directly posts the tweet early and cancels the scheduler task.
+        #[pallet::call_index(34)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::publish_scheduled_tweet()
+        // Looking up the mentioned users and whether they blocked the author
+        .saturating_add(T::DbWeight::get().reads(2 * T::MaxMentions::get() as u64)))]
+        pub fn publish_scheduled_tweet(origin: OriginFor<T>, schedule_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let name = Names::<T>::get(&who).ok_or(Error::<T>::UserNotFound)?;
+            let scheduled = Self::take_scheduled_tweet(&who, schedule_id)?;
+            // The task is already off the agenda if the scheduler dispatched this
+            let _ = T::Scheduler::cancel_named(Self::schedule_task(&who, schedule_id));
+            Self::post_tweet(&who, name, scheduled.content, None)?;
+            Ok(())
+        }
+
+        /// Post a tweet that is removed again once block `expires_at` is reached. Removal happens
+        /// in `on_idle`, so under load it can take a few blocks longer. `ExpiringTweetDeposit` is
+        /// held from the caller until the tweet is removed, whether it expires or is deleted.
+        #[pallet::call_index(35)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_expiring_tweet(
+            name.len() as u32,
+            password.len() as u32,
+            content.len() as u32,
+        )
+        // Looking up the mentioned users and whether they blocked the author
+        .saturating_add(T::DbWeight::get().reads(2 * T::MaxMentions::get() as u64))
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn create_expiring_tweet(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            content: BoundedVec<u8, ConstU32<280>>,
+            expires_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who =
+                Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Post)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(expires_at > now, Error::<T>::ExpiryInPast);
+
+            let tweet_id = Self::post_tweet(&who, name, content, Some(expires_at))?;
+            let deposit = T::ExpiringTweetDeposit::get();
+            T::Currency::hold(&HoldReason::ExpiringTweetDeposit.into(), &who, deposit)?;
+            ExpiryDeposits::<T>::insert(&who, tweet_id, deposit);
+            ExpiringAt::<T>::try_append(expires_at, (who, tweet_id))
+                .map_err(|_| Error::<T>::TooManyTweetsExpiring)?;
+            if ExpiryCursor::<T>::get().is_none() {
+                ExpiryCursor::<T>::put(now);
+            }
+            Ok(())
+        }
+
+        /// Pin tweet `tweet_id` of `name` to the top of its profile.
+        #[pallet::call_index(36)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::pin_tweet(
+            name.len() as u32,
+            password.len() as u32,
+        )
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn pin_tweet(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        ) -> DispatchResult {
+            let who =
+                Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Profile)?;
+            ensure!(Tweets::<T>::contains_key(&name, tweet_id), Error::<T>::TweetNotFound);
+
+            PinnedTweets::<T>::try_mutate(&who, |pinned| {
+                ensure!(!pinned.contains(&tweet_id), Error::<T>::AlreadyPinned);
+                pinned.try_push(tweet_id).map_err(|_| Error::<T>::TooManyPins)
+            })?;
+
+            Self::deposit_event(Event::TweetPinned { name, tweet_id });
+            Ok(())
+        }
+
+        #[pallet::call_index(37)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unpin_tweet(
+            name.len() as u32,
+            password.len() as u32,
+        )
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn unpin_tweet(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        ) -> DispatchResult {
+            let who =
+                Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Profile)?;
+
+            PinnedTweets::<T>::try_mutate(&who, |pinned| {
+                let index =
+                    pinned.iter().position(|id| *id == tweet_id).ok_or(Error::<T>::NotPinned)?;
+                pinned.remove(index);
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::TweetUnpinned { name, tweet_id });
+            Ok(())
+        }
+
+        /// Bookmark tweet `tweet_id` of `author` for the caller, unless `author` blocked them.
+        #[pallet::call_index(38)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::bookmark(author.len() as u32))]
+        pub fn bookmark(
+            origin: OriginFor<T>,
+            author: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Names::<T>::contains_key(&who), Error::<T>::UserNotFound);
+            let tweet = (Self::tweet_author(&author, tweet_id)?, tweet_id);
+            Self::ensure_not_blocked(&who, &tweet.0)?;
+
+            Bookmarks::<T>::try_mutate(&who, |bookmarks| {
+                ensure!(!bookmarks.contains(&tweet), Error::<T>::AlreadyBookmarked);
+                bookmarks.try_push(tweet.clone()).map_err(|_| Error::<T>::TooManyBookmarks)
+            })?;
+            BookmarkedBy::<T>::insert(&tweet, &who, ());
+
+            Self::deposit_event(Event::TweetBookmarked { who, author, tweet_id });
+            Ok(())
+        }
+
+        #[pallet::call_index(39)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unbookmark(author.len() as u32))]
+        pub fn unbookmark(
+            origin: OriginFor<T>,
+            author: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let author_account = AccountByName::<T>::get(&author).ok_or(Error::<T>::UserNotFound)?;
+            let tweet = (author_account, tweet_id);
+
+            Bookmarks::<T>::try_mutate(&who, |bookmarks| {
+                let index = bookmarks
+                    .iter()
+                    .position(|bookmark| bookmark == &tweet)
+                    .ok_or(Error::<T>::NotBookmarked)?;
+                bookmarks.remove(index);
+                Ok::<_, DispatchError>(())
+            })?;
+            BookmarkedBy::<T>::remove(&tweet, &who);
+
+            Self::deposit_event(Event::BookmarkRemoved { who, author, tweet_id });
+            Ok(())
+        }
+
+        /// Create an empty list `list` of users for `name`. Private lists are left out of the
+        /// runtime API for anyone but their owner.
+        #[pallet::call_index(40)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_list(
+            name.len() as u32,
+            password.len() as u32,
+        )
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn create_list(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            list: BoundedVec<u8, ConstU32<64>>,
+            private: bool,
+        ) -> DispatchResult {
+            Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Profile)?;
+            ensure!(!Lists::<T>::contains_key(&name, &list), Error::<T>::ListAlreadyExists);
+
+            let count = ListCount::<T>::get(&name);
+            ensure!(count < T::MaxListsPerUser::get(), Error::<T>::TooManyLists);
+            ListCount::<T>::insert(&name, count + 1);
+            Lists::<T>::insert(&name, &list, UserList { members: BoundedVec::new(), private });
+
+            Self::deposit_event(Event::ListCreated { name, list, private });
+            Ok(())
+        }
+
+        #[pallet::call_index(41)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::add_to_list(
+            name.len().max(member.len()) as u32,
+            password.len() as u32,
+        )
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn add_to_list(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            list: BoundedVec<u8, ConstU32<64>>,
+            member: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Profile)?;
+            let account = AccountByName::<T>::get(&member).ok_or(Error::<T>::UserNotFound)?;
+
+            Lists::<T>::try_mutate(&name, &list, |user_list| {
+                let user_list = user_list.as_mut().ok_or(Error::<T>::ListNotFound)?;
+                ensure!(!user_list.members.contains(&account), Error::<T>::AlreadyInList);
+                user_list.members.try_push(account).map_err(|_| Error::<T>::ListFull)
+            })?;
+
+            Self::deposit_event(Event::AddedToList { name, list, member });
+            Ok(())
+        }
+
+        #[pallet::call_index(42)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_from_list(
+            name.len().max(member.len()) as u32,
+            password.len() as u32,
+        )
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn remove_from_list(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            list: BoundedVec<u8, ConstU32<64>>,
+            member: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Profile)?;
+            let account = AccountByName::<T>::get(&member).ok_or(Error::<T>::UserNotFound)?;
+
+            Lists::<T>::try_mutate(&name, &list, |user_list| {
+                let user_list = user_list.as_mut().ok_or(Error::<T>::ListNotFound)?;
+                let index = user_list
+                    .members
+                    .iter()
+                    .position(|m| *m == account)
+                    .ok_or(Error::<T>::NotInList)?;
+                user_list.members.remove(index);
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::RemovedFromList { name, list, member });
+            Ok(())
+        }
+
+        #[pallet::call_index(43)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::delete_list(
+            name.len() as u32,
+            password.len() as u32,
+        )
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn delete_list(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            list: BoundedVec<u8, ConstU32<64>>,
+        ) -> DispatchResult {
+            Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Profile)?;
+            ensure!(Lists::<T>::take(&name, &list).is_some(), Error::<T>::ListNotFound);
+            ListCount::<T>::mutate(&name, |count| *count = count.saturating_sub(1));
+
+            Self::deposit_event(Event::ListDeleted { name, list });
+            Ok(())
+        }
+
+        /// Tip the author of tweet `tweet_id` of `name` with `amount` of the caller's balance.
+        #[pallet::call_index(44)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::tip_tweet(name.len() as u32))]
+        pub fn tip_tweet(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::ZeroTip);
+            let author = Self::tweet_author(&name, tweet_id)?;
+            ensure!(author != who, Error::<T>::CannotTargetSelf);
+            Self::ensure_not_blocked(&who, &author)?;
+
+            T::Currency::transfer(&who, &author, amount, Preservation::Preserve)?;
+            Tips::<T>::mutate(&author, tweet_id, |total| *total = total.saturating_add(amount));
+
+            Self::deposit_event(Event::Tipped { who, name, tweet_id, amount });
+            Ok(())
+        }
+
+        /// Tip the author of tweet `tweet_id` of `name` with `amount` of the caller's asset
+        /// `asset_id`.
+        #[pallet::call_index(45)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::tip_tweet_with_asset(
+            name.len() as u32,
+        ))]
+        pub fn tip_tweet_with_asset(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+            asset_id: AssetIdOf<T>,
+            amount: AssetBalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::ZeroTip);
+            let author = Self::tweet_author(&name, tweet_id)?;
+            ensure!(author != who, Error::<T>::CannotTargetSelf);
+            Self::ensure_not_blocked(&who, &author)?;
+
+            AssetTips::<T>::try_mutate(&author, tweet_id, |tips| {
+                match tips.iter_mut().find(|(id, _)| *id == asset_id) {
+                    Some((_, total)) => *total = total.saturating_add(amount),
+                    None => tips
+                        .try_push((asset_id.clone(), amount))
+                        .map_err(|_| Error::<T>::TooManyTipAssets)?,
+                }
+                Ok::<_, DispatchError>(())
+            })?;
+            <T::Assets as fungibles::Mutate<_>>::transfer(
+                asset_id.clone(),
+                &who,
+                &author,
+                amount,
+                Preservation::Preserve,
+            )?;
+
+            Self::deposit_event(Event::TippedWithAsset { who, name, tweet_id, asset_id, amount });
+            Ok(())
+        }
+
+        /// Show item `item` of NFT collection `collection`, which the caller must own, as the
+        /// avatar of `name`. It is removed again once the NFT changes hands, see `Avatars`.
+        #[pallet::call_index(46)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_avatar(
+            name.len() as u32,
+            password.len() as u32,
+        )
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn set_avatar(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            collection: T::NftCollectionId,
+            item: T::NftId,
+        ) -> DispatchResult {
+            let who =
+                Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Profile)?;
+            ensure!(
+                T::Nfts::owner(&collection, &item) == Some(who.clone()),
+                Error::<T>::NotNftOwner
+            );
+
+            Avatars::<T>::insert(&who, (collection, item));
+
+            Self::deposit_event(Event::AvatarSet { name, collection, item });
+            Ok(())
+        }
+
+        /// Let `device` make calls for `name` within `scopes` until block `expires_at`, or
+        /// update the scopes and expiry of a device added before. Devices still need the password
+        /// of `name`, and unlike the calls of the name's own account theirs are charged fees.
+        #[pallet::call_index(48)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::add_device(
+            name.len() as u32,
+            password.len() as u32,
+        ))]
+        pub fn add_device(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            device: T::AccountId,
+            scopes: BoundedVec<DeviceScope, ConstU32<3>>,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+            ensure!(device != who, Error::<T>::CannotTargetSelf);
+            if let Some(expires_at) = expires_at {
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(expires_at > now, Error::<T>::ExpiryInPast);
+            }
+
+            if !Devices::<T>::contains_key(&who, &device) {
+                let count = DeviceCount::<T>::get(&who);
+                ensure!(count < T::MaxDevices::get(), Error::<T>::TooManyDevices);
+                DeviceCount::<T>::insert(&who, count + 1);
+            }
+            Devices::<T>::insert(&who, &device, Device { scopes: scopes.clone(), expires_at });
+
+            Self::deposit_event(Event::DeviceAdded { name, device, scopes, expires_at });
+            Ok(())
+        }
+
+        #[pallet::call_index(49)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_device(
+            name.len() as u32,
+            password.len() as u32,
+        ))]
+        pub fn remove_device(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            device: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+            ensure!(Devices::<T>::take(&who, &device).is_some(), Error::<T>::DeviceNotFound);
+            DeviceCount::<T>::mutate(&who, |count| *count = count.saturating_sub(1));
+
+            Self::deposit_event(Event::DeviceRemoved { name, device });
+            Ok(())
+        }
+
+        /// Clear the avatar of `name`. Only the user and their devices with the `Profile` scope
+        /// can, unless its NFT changed hands, in which case anyone can.
+        #[pallet::call_index(47)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::clear_avatar(name.len() as u32)
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn clear_avatar(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let account = AccountByName::<T>::get(&name).ok_or(Error::<T>::UserNotFound)?;
+            let (collection, item) = Avatars::<T>::get(&account).ok_or(Error::<T>::NoAvatar)?;
+            ensure!(
+                Self::ensure_acts_for(&who, &account, DeviceScope::Profile).is_ok() ||
+                    T::Nfts::owner(&collection, &item) != Some(account.clone()),
+                Error::<T>::AvatarStillOwned
+            );
+
+            Avatars::<T>::remove(&account);
+
+            Self::deposit_event(Event::AvatarCleared { name });
+            Ok(())
+        }
+
+        /// Let `threshold` of the users registered as `friends` recover `name` to a new account
+        /// once `delay` blocks passed since the recovery was initiated, see
+        /// `initiate_recovery`. Replaces the config set before. Friends are stored as the
+        /// accounts their names are registered to, so they keep their place when they rename.
+        #[pallet::call_index(50)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_recovery(
+            name.len() as u32,
+            password.len() as u32,
+            friends.len() as u32,
+        ))]
+        pub fn set_recovery(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            friends: BoundedVec<BoundedVec<u8, ConstU32<256>>, T::MaxFriends>,
+            threshold: u32,
+            delay: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+            Self::ensure_no_active_recovery(&name)?;
+            ensure!(
+                threshold > 0 && threshold as usize <= friends.len(),
+                Error::<T>::InvalidRecoveryThreshold
+            );
+
+            let mut accounts = Vec::with_capacity(friends.len());
+            for friend in &friends {
+                let account = AccountByName::<T>::get(friend).ok_or(Error::<T>::UserNotFound)?;
+                ensure!(account != who, Error::<T>::CannotTargetSelf);
+                ensure!(!accounts.contains(&account), Error::<T>::DuplicateFriend);
+                accounts.push(account);
+            }
+            let friends = BoundedVec::truncate_from(accounts);
+            Recoveries::<T>::insert(
+                &name,
+                RecoveryConfig { friends: friends.clone(), threshold, delay },
+            );
+
+            Self::deposit_event(Event::RecoverySet { name, friends, threshold, delay });
+            Ok(())
+        }
+
+        #[pallet::call_index(51)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_recovery(
+            name.len() as u32,
+            password.len() as u32,
+        ))]
+        pub fn remove_recovery(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+            Self::ensure_no_active_recovery(&name)?;
+            ensure!(Recoveries::<T>::take(&name).is_some(), Error::<T>::NotRecoverable);
+
+            Self::deposit_event(Event::RecoveryRemoved { name });
+            Ok(())
+        }
+
+        /// Start recovering `name` to the caller's account, holding `RecoveryDeposit` from it.
+        /// The caller can't have a name of their own. The owner can't rename, delete, hand over
+        /// or reconfigure the name once a friend vouched for the recovery, until it is claimed
+        /// or cancelled.
+        #[pallet::call_index(52)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::initiate_recovery(name.len() as u32))]
+        pub fn initiate_recovery(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let account = AccountByName::<T>::get(&name).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(account != who, Error::<T>::CannotTargetSelf);
+            ensure!(Recoveries::<T>::contains_key(&name), Error::<T>::NotRecoverable);
+            ensure!(!Names::<T>::contains_key(&who), Error::<T>::AccountHasName);
+            ensure!(
+                !ActiveRecoveries::<T>::contains_key(&name, &who),
+                Error::<T>::RecoveryAlreadyStarted
+            );
+
+            let deposit = T::RecoveryDeposit::get();
+            T::Currency::hold(&HoldReason::RecoveryDeposit.into(), &who, deposit)?;
+            let recovery = ActiveRecovery {
+                started_at: frame_system::Pallet::<T>::block_number(),
+                deposit,
+                vouchers: BoundedVec::new(),
+            };
+            ActiveRecoveries::<T>::insert(&name, &who, recovery);
+
+            Self::deposit_event(Event::RecoveryInitiated { name, rescuer: who });
+            Ok(())
+        }
+
+        /// Vouch, as one of the friends in its recovery config who still has a registered name,
+        /// for recovering `name` to `rescuer`.
+        #[pallet::call_index(53)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::vouch(name.len() as u32))]
+        pub fn vouch(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            rescuer: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let config = Recoveries::<T>::get(&name).ok_or(Error::<T>::NotRecoverable)?;
+            ensure!(config.friends.contains(&who), Error::<T>::NotFriend);
+            ensure!(Names::<T>::contains_key(&who), Error::<T>::UserNotFound);
+
+            let first = ActiveRecoveries::<T>::try_mutate(&name, &rescuer, |recovery| {
+                let recovery = recovery.as_mut().ok_or(Error::<T>::RecoveryNotFound)?;
+                ensure!(!recovery.vouchers.contains(&who), Error::<T>::AlreadyVouched);
+                // Vouchers are distinct friends, so there is room for every friend
+                recovery.vouchers.try_push(who.clone()).map_err(|_| Error::<T>::AlreadyVouched)?;
+                Ok::<_, Error<T>>(recovery.vouchers.len() == 1)
+            })?;
+            if first {
+                VouchedRecoveries::<T>::mutate(&name, |count| count.saturating_inc());
+            }
+
+            Self::deposit_event(Event::RecoveryVouched { name, rescuer, friend: who });
+            Ok(())
+        }
+
+        /// Rebind `name` to the caller's account, once enough friends vouched for the recovery
+        /// the caller initiated and the recovery delay passed. Releases the caller's deposit and
+        /// sets `new_password` as the name's password, as the old one may have leaked with the
+        /// lost key. See `move_name` for what moves along with the name.
+        #[pallet::call_index(54)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::claim_recovery(
+            name.len() as u32,
+            new_password.len() as u32,
+            T::MaxTweetsPerUser::get(),
+        ))]
+        pub fn claim_recovery(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            new_password: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let config = Recoveries::<T>::get(&name).ok_or(Error::<T>::NotRecoverable)?;
+            let recovery =
+                ActiveRecoveries::<T>::get(&name, &who).ok_or(Error::<T>::RecoveryNotFound)?;
+            ensure!(
+                recovery.vouchers.len() as u32 >= config.threshold,
+                Error::<T>::ThresholdNotReached
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                now >= recovery.started_at.saturating_add(config.delay),
+                Error::<T>::RecoveryDelayNotPassed
+            );
+            ensure!(!Names::<T>::contains_key(&who), Error::<T>::AccountHasName);
+            Self::ensure_not_suspended(&who)?;
+            let lost = AccountByName::<T>::get(&name).ok_or(Error::<T>::UserNotFound)?;
+
+            ActiveRecoveries::<T>::remove(&name, &who);
+            Self::forget_vouched_recovery(&name, &recovery);
+            T::Currency::release(
+                &HoldReason::RecoveryDeposit.into(),
+                &who,
+                recovery.deposit,
+                Precision::BestEffort,
+            )?;
+            // The lost account's key may have listed the name for sale
+            NamesForSale::<T>::remove(&name);
+            let tweet_count = Self::move_name(&name, &lost, &who);
+            PasswordHash::<T>::insert(&name, 0, blake2_256(&new_password[..]));
+
+            Self::deposit_event(Event::NameRecovered { name: name.clone(), from: lost, to: who });
+            Self::deposit_event(Event::PasswordHashed { name: name.clone() });
+
+            // Only charge for the tweets we actually moved
+            Ok(Some(<T as pallet::Config>::WeightInfo::claim_recovery(
+                name.len() as u32,
+                new_password.len() as u32,
+                tweet_count,
+            ))
+            .into())
+        }
+
+        /// Cancel the recovery of `name` to `rescuer`. The deposit of `rescuer` goes to the
+        /// name's owner.
+        #[pallet::call_index(55)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_recovery(
+            name.len() as u32,
+            password.len() as u32,
+        ))]
+        pub fn cancel_recovery(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            rescuer: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+            let recovery =
+                ActiveRecoveries::<T>::take(&name, &rescuer).ok_or(Error::<T>::RecoveryNotFound)?;
+            Self::forget_vouched_recovery(&name, &recovery);
+            T::Currency::transfer_on_hold(
+                &HoldReason::RecoveryDeposit.into(),
+                &rescuer,
+                &who,
+                recovery.deposit,
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Polite,
+            )?;
+
+            Self::deposit_event(Event::RecoveryCancelled { name, rescuer });
+            Ok(())
+        }
+
+        /// Hand `name` to the account `to`, which can't have a name yet. The password stays the
+        /// same, so `to` needs it from the caller. See `hand_over_name` for what goes along
+        /// with the name.
+        #[pallet::call_index(56)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_name(
+            name.len() as u32,
+            password.len() as u32,
+            T::MaxTweetsPerUser::get(),
+        ))]
+        pub fn transfer_name(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            to: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+            Self::ensure_transferable(&name, &who, &to)?;
+
+            let tweet_count = Self::hand_over_name(&name, &who, &to);
+
+            Self::deposit_event(Event::NameTransferred { name: name.clone(), from: who, to });
+
+            // Only charge for the tweets we actually moved
+            Ok(Some(<T as pallet::Config>::WeightInfo::transfer_name(
+                name.len() as u32,
+                password.len() as u32,
+                tweet_count,
+            ))
+            .into())
+        }
+
+        /// Offer `name` to anyone without a name for `price`, see `buy_name`. Listing the name
+        /// again changes the price.
+        #[pallet::call_index(57)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::list_name_for_sale(
+            name.len() as u32,
+            password.len() as u32,
+        ))]
+        pub fn list_name_for_sale(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+            Self::ensure_not_suspended(&who)?;
+
+            NamesForSale::<T>::insert(&name, price);
+
+            Self::deposit_event(Event::NameListed { name, price });
+            Ok(())
+        }
+
+        #[pallet::call_index(58)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unlist_name(
+            name.len() as u32,
+            password.len() as u32,
+        ))]
+        pub fn unlist_name(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            password: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authenticate(&who, &name, &password)?;
+            ensure!(NamesForSale::<T>::take(&name).is_some(), Error::<T>::NotForSale);
+
+            Self::deposit_event(Event::NameUnlisted { name });
+            Ok(())
+        }
+
+        /// Buy `name` for `price`, which has to be the price it is listed at, and set
+        /// `new_password` as its password. The caller can't have a name yet. See
+        /// `hand_over_name` for what goes along with the name.
+        #[pallet::call_index(59)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::buy_name(
+            name.len() as u32,
+            new_password.len() as u32,
+            T::MaxTweetsPerUser::get(),
+        ))]
+        pub fn buy_name(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, ConstU32<256>>,
+            price: BalanceOf<T>,
+            new_password: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let listed = NamesForSale::<T>::get(&name).ok_or(Error::<T>::NotForSale)?;
+            ensure!(listed == price, Error::<T>::SalePriceChanged);
+            let seller = AccountByName::<T>::get(&name).ok_or(Error::<T>::UserNotFound)?;
+            Self::ensure_transferable(&name, &seller, &who)?;
+
+            T::Currency::transfer(&who, &seller, price, Preservation::Preserve)?;
+            let tweet_count = Self::hand_over_name(&name, &seller, &who);
+            PasswordHash::<T>::insert(&name, 0, blake2_256(&new_password[..]));
+
+            Self::deposit_event(Event::NameSold {
+                name: name.clone(),
+                from: seller,
+                to: who,
+                price,
+            });
+            Self::deposit_event(Event::PasswordHashed { name: name.clone() });
+
+            // Only charge for the tweets we actually moved
+            Ok(Some(<T as pallet::Config>::WeightInfo::buy_name(
+                name.len() as u32,
+                new_password.len() as u32,
+                tweet_count,
+            ))
+            .into())
+        }
+
+        /// Post a tweet with a poll on 2 to 4 options that stays open until block `ends_at`.
+        #[pallet::call_index(30)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_poll_tweet(
+            name.len() as u32,
+            password.len() as u32,
+            content.len() as u32,
+        )
+        // Looking up the mentioned users and whether they blocked the author
+        .saturating_add(T::DbWeight::get().reads(2 * T::MaxMentions::get() as u64))
+        // Looking up the caller's device key
+        .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn create_poll_tweet(
             origin: OriginFor<T>,
             name: BoundedVec<u8, ConstU32<256>>,
             password: BoundedVec<u8, ConstU32<256>>,
-            content: BoundedVec<u8, ConstU32<280>>
+            content: BoundedVec<u8, ConstU32<280>>,
+            options: BoundedVec<BoundedVec<u8, ConstU32<64>>, ConstU32<4>>,
+            ends_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            ensure!(
+                options.len() >= 2 && options.iter().all(|option| !option.is_empty()),
+                Error::<T>::InvalidPollOptions
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                ends_at > now && ends_at <= now.saturating_add(T::MaxPollDuration::get()),
+                Error::<T>::InvalidPollEnd
+            );
+
+            let who =
+                Self::authorize(&ensure_signed(origin)?, &name, &password, DeviceScope::Post)?;
+            let tweet_id = Self::post_tweet(&who, name.clone(), content, None)?;
+
+            PollsEndingAt::<T>::try_append(ends_at, (who.clone(), tweet_id))
+                .map_err(|_| Error::<T>::TooManyPollsEnding)?;
+            let index = NextPollIndex::<T>::get();
+            NextPollIndex::<T>::put(index.saturating_add(1));
+            let tallies = BoundedVec::truncate_from(vec![0; options.len()]);
+            Polls::<T>::insert(&who, tweet_id, Poll { index, options, tallies, ends_at, closed: false });
+
+            Self::deposit_event(Event::PollCreated { name, tweet_id, ends_at });
+            Ok(())
+        }
+
+        /// Vote for option `option` of the poll on tweet `tweet_id` of `author`. Every
+        /// registered user gets one vote per poll.
+        #[pallet::call_index(31)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::vote_poll(author.len() as u32))]
+        pub fn vote_poll(
+            origin: OriginFor<T>,
+            author: BoundedVec<u8, ConstU32<256>>,
+            tweet_id: u32,
+            option: u8,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            let name = Names::<T>::get(&who).ok_or(Error::<T>::UserNotFound)?;
+            Self::ensure_not_suspended(&who)?;
 
-            
-            // Verify the user exists and owns this name
-            let account = AccountByName::<T>::get(&name)
-                .ok_or(Error::<T>::UserNotFound)?;
-            ensure!(account == who, Error::<T>::NotAuthorized);
+            let author_account = AccountByName::<T>::get(&author).ok_or(Error::<T>::UserNotFound)?;
+            Self::ensure_not_blocked(&who, &author_account)?;
 
-            // Verify password
-            let stored_hash = PasswordHash::<T>::get(&name,0 )
-                .ok_or(Error::<T>::UserNotFound)?;
-            let password_hash = blake2_256(&password[..]);
-            ensure!(password_hash == stored_hash, Error::<T>::InvalidCredentials);
+            let mut poll =
+                Polls::<T>::get(&author_account, tweet_id).ok_or(Error::<T>::PollNotFound)?;
+            ensure!(!poll.closed, Error::<T>::PollClosed);
+            let voter = Self::voter_id(&who);
+            ensure!(!PollVotes::<T>::contains_key(poll.index, voter), Error::<T>::AlreadyVoted);
+            let tally =
+                poll.tallies.get_mut(option as usize).ok_or(Error::<T>::InvalidPollOption)?;
+            *tally = tally.saturating_add(1);
+
+            PollVotes::<T>::insert(poll.index, voter, option);
+            Polls::<T>::insert(&author_account, tweet_id, poll);
+
+            Self::deposit_event(Event::PollVoted { name, author, tweet_id, option });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The suspension or ban currently in effect for `who`, if any.
+        pub fn active_suspension(who: &T::AccountId) -> Option<Suspension<BlockNumberFor<T>>> {
+            Suspended::<T>::get(who).filter(|suspension| match suspension {
+                Suspension::Until(until) => frame_system::Pallet::<T>::block_number() < *until,
+                Suspension::Indefinite | Suspension::Banned => true,
+            })
+        }
+
+        /// Ensure `who` is neither suspended nor banned.
+        fn ensure_not_suspended(who: &T::AccountId) -> DispatchResult {
+            match Self::active_suspension(who) {
+                None => Ok(()),
+                Some(Suspension::Banned) => Err(Error::<T>::Banned.into()),
+                Some(_) => Err(Error::<T>::Suspended.into()),
+            }
+        }
+
+        /// Post `content` as the tweet with the next ID of `name`, registered to `who`, and
+        /// return that ID. Callers authenticate `who` first.
+        fn post_tweet(
+            who: &T::AccountId,
+            name: BoundedVec<u8, ConstU32<256>>,
+            content: BoundedVec<u8, ConstU32<280>>,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> Result<u32, DispatchError> {
+            Self::ensure_not_suspended(who)?;
+
+            // Users can't mention people who blocked them
+            let mut mentions = 0u32;
+            for mentioned in Self::mentions(&content) {
+                mentions += 1;
+                ensure!(mentions <= T::MaxMentions::get(), Error::<T>::TooManyMentions);
+                Self::ensure_not_blocked_by_name(who, mentioned)?;
+            }
 
             // Get current tweet count for name
             let tweet_id = TweetCount::<T>::get(&name);
+            ensure!(tweet_id < T::MaxTweetsPerUser::get(), Error::<T>::TooManyTweets);
 
             // Get current timestamp
             let now = pallet_timestamp::Pallet::<T>::get();
@@ -266,6 +3221,7 @@ pub mod pallet {
                 name: name.clone(),
                 content,
                 timestamp,
+                expires_at,
             };
 
             Tweets::<T>::insert(&name, tweet_id, tweet);
@@ -277,199 +3233,626 @@ pub mod pallet {
                 timestamp,
             });
 
-            Ok(())
+            Ok(tweet_id)
         }
 
-        #[pallet::call_index(3)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::get_user_birthday())]
-        pub fn get_user_birthday(
-            origin: OriginFor<T>,
-            name: BoundedVec<u8, ConstU32<256>>,
-        ) -> DispatchResult {
-            ensure_signed(origin)?;
-
-            let account = AccountByName::<T>::get(&name)
-                .ok_or(Error::<T>::UserNotFound)?;
+        /// Name of the scheduler task publishing tweet `schedule_id` of `who`.
+        fn schedule_task(who: &T::AccountId, schedule_id: u32) -> schedule::v3::TaskName {
+            (b"TemplateModule/scheduled_tweet", who, schedule_id).using_encoded(blake2_256)
+        }
 
-            let birthday = Birthdays::<T>::get(&name, 0)
-                .ok_or(Error::<T>::UserNotFound)?;
+        /// Remove tweet `schedule_id` from the tweets `who` has scheduled.
+        fn take_scheduled_tweet(
+            who: &T::AccountId,
+            schedule_id: u32,
+        ) -> Result<ScheduledTweet<BlockNumberFor<T>>, DispatchError> {
+            ScheduledTweets::<T>::try_mutate(who, |scheduled| {
+                let index = scheduled
+                    .iter()
+                    .position(|tweet| tweet.id == schedule_id)
+                    .ok_or(Error::<T>::ScheduledTweetNotFound)?;
+                Ok(scheduled.remove(index))
+            })
+        }
 
-            log::info!("User {:?} has birthday: {:?}", name, birthday);
-            Ok(())
+        /// Drop every tweet `who` has scheduled, along with the scheduler tasks publishing them.
+        fn cancel_scheduled_tweets(who: &T::AccountId) {
+            for scheduled in ScheduledTweets::<T>::take(who) {
+                // The task is already gone if publishing it failed
+                let _ = T::Scheduler::cancel_named(Self::schedule_task(who, scheduled.id));
+            }
         }
 
-        #[pallet::call_index(4)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::get_user_tweets())]
-        pub fn get_user_tweets(
-            origin: OriginFor<T>,
-            name: BoundedVec<u8, ConstU32<256>>,
-        ) -> DispatchResult {
-            ensure_signed(origin)?;
+        /// Remove expired tweets, oldest first, as far as `meter` allows.
+        fn remove_expired_tweets(now: BlockNumberFor<T>, meter: &mut WeightMeter) {
+            // Reading and writing the cursor
+            if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+                return;
+            }
+            let Some(mut cursor) = ExpiryCursor::<T>::get() else {
+                return;
+            };
 
-            ensure!(AccountByName::<T>::contains_key(&name), Error::<T>::UserNotFound);
+            let per_block = <T as pallet::Config>::WeightInfo::expire_tweets(0);
+            let per_tweet = <T as pallet::Config>::WeightInfo::expire_tweets(1)
+                .saturating_sub(per_block);
+            while cursor <= now && meter.try_consume(per_block).is_ok() {
+                let mut expiring = ExpiringAt::<T>::get(cursor);
+                while let Some((author, tweet_id)) = expiring.last() {
+                    if meter.try_consume(per_tweet).is_err() {
+                        break;
+                    }
+                    Self::expire_tweet(author, *tweet_id, cursor);
+                    expiring.pop();
+                }
 
-            let tweet_count = TweetCount::<T>::get(&name);
-            for tweet_id in 0..tweet_count {
-                if let Some(tweet) = Tweets::<T>::get(&name, tweet_id) {
-                    log::info!("Tweet ID {}: {:?}", tweet_id, tweet);
+                if !expiring.is_empty() {
+                    ExpiringAt::<T>::insert(cursor, expiring);
+                    break;
                 }
+                ExpiringAt::<T>::remove(cursor);
+                cursor.saturating_inc();
             }
-            Ok(())
-        }
-
-        #[pallet::call_index(5)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::update_user_name())]
-        pub fn update_user_name(
-            origin: OriginFor<T>,
-            name: BoundedVec<u8, ConstU32<256>>,
-            password: BoundedVec<u8, ConstU32<256>>,
-            new_name: BoundedVec<u8, ConstU32<256>>,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
 
-            let account = AccountByName::<T>::get(&name)
-                .ok_or(Error::<T>::UserNotFound)?;
-            ensure!(account == who, Error::<T>::NotAuthorized);
+            ExpiryCursor::<T>::put(cursor);
+        }
 
-            let stored_hash = PasswordHash::<T>::get(&name, 0)
-                .ok_or(Error::<T>::UserNotFound)?;
+        /// Remove the bookmarks of deleted tweets from the bookmarkers' lists, as far as `meter`
+        /// allows.
+        fn remove_stale_bookmarks(meter: &mut WeightMeter) {
+            let per_tweet = <T as pallet::Config>::WeightInfo::remove_stale_bookmarks(0);
+            let per_bookmark = <T as pallet::Config>::WeightInfo::remove_stale_bookmarks(1)
+                .saturating_sub(per_tweet);
+            while meter.try_consume(per_tweet).is_ok() {
+                let Some(tweet) = StaleBookmarks::<T>::iter_keys().next() else {
+                    return;
+                };
+                let mut bookmarkers = BookmarkedBy::<T>::drain_prefix(&tweet);
+                loop {
+                    if meter.try_consume(per_bookmark).is_err() {
+                        return;
+                    }
+                    let Some((bookmarker, ())) = bookmarkers.next() else {
+                        break;
+                    };
+                    Bookmarks::<T>::mutate(&bookmarker, |bookmarks| {
+                        bookmarks.retain(|bookmark| bookmark != &tweet)
+                    });
+                }
+                StaleBookmarks::<T>::remove(&tweet);
+            }
+        }
 
-            let password_hash = blake2_256(&password[..]);
-            ensure!(password_hash == stored_hash, Error::<T>::InvalidCredentials);
+        /// Remove avatars whose NFT the user no longer owns, at most `MaxAvatarChecksPerBlock`
+        /// of them and as far as `meter` allows, continuing after the last one checked before.
+        fn remove_stale_avatars(meter: &mut WeightMeter) {
+            let per_block = <T as pallet::Config>::WeightInfo::remove_stale_avatars(0);
+            let per_avatar = <T as pallet::Config>::WeightInfo::remove_stale_avatars(1)
+                .saturating_sub(per_block);
+            if meter.try_consume(per_block).is_err() {
+                return;
+            }
 
-            // Ensure new name isn't taken
-            ensure!(!AccountByName::<T>::contains_key(&new_name), Error::<T>::NameAlreadyTaken);
+            let mut avatars = match AvatarCursor::<T>::get() {
+                Some(cursor) => Avatars::<T>::iter_from(cursor.into_inner()),
+                None => Avatars::<T>::iter(),
+            };
+            for _ in 0..T::MaxAvatarChecksPerBlock::get() {
+                if meter.try_consume(per_avatar).is_err() {
+                    break;
+                }
+                let Some((account, (collection, item))) = avatars.next() else {
+                    AvatarCursor::<T>::kill();
+                    return;
+                };
+                if T::Nfts::owner(&collection, &item).as_ref() != Some(&account) {
+                    Avatars::<T>::remove(&account);
+                    if let Some(name) = Names::<T>::get(&account) {
+                        Self::deposit_event(Event::AvatarCleared { name });
+                    }
+                }
+            }
+            // Keys too long for the cursor make the next check start over
+            AvatarCursor::<T>::set(avatars.last_raw_key().to_vec().try_into().ok());
+        }
 
-            // Remove old name mapping
-            Names::<T>::remove(&who);
-            PasswordHash::<T>::remove(&name, 0);
-            AccountByName::<T>::remove(&name);
+        /// Remove tweet `tweet_id` of `author` if it is still the tweet that expires at `at`, and
+        /// not one that was posted under a reused ID after the expiring one was deleted.
+        fn expire_tweet(author: &T::AccountId, tweet_id: u32, at: BlockNumberFor<T>) {
+            let Some(name) = Names::<T>::get(author) else {
+                return;
+            };
+            let expired = Tweets::<T>::mutate_exists(&name, tweet_id, |tweet| {
+                let expired = tweet.as_ref().is_some_and(|tweet| tweet.expires_at == Some(at));
+                if expired {
+                    *tweet = None;
+                }
+                expired
+            });
+            if expired {
+                Self::forget_tweet(author, tweet_id);
+                Self::deposit_event(Event::TweetExpired { name, tweet_id });
+            }
+        }
 
-            // Store new name
-            Names::<T>::insert(&who, new_name.clone());
-            AccountByName::<T>::insert(&new_name, who.clone());
-            PasswordHash::<T>::insert(&new_name, 0, password_hash);
+        /// Remove the reports, hiding, pending appeal, poll, tip total and pin of a tweet that no
+        /// longer exists, release its expiry deposit and queue its bookmarks for removal in
+        /// `on_idle`.
+        fn forget_tweet(author: &T::AccountId, tweet_id: u32) {
+            Self::release_expiry_deposit(author, tweet_id);
+            Reports::<T>::remove(author, tweet_id);
+            HiddenTweets::<T>::remove(author, tweet_id);
+            Appeals::<T>::remove(author, AppealSubject::Tweet(tweet_id));
+            if let Some(poll) = Polls::<T>::take(author, tweet_id) {
+                if !poll.closed {
+                    PollsEndingAt::<T>::mutate(poll.ends_at, |ending| {
+                        ending.retain(|(a, id)| (a, *id) != (author, tweet_id))
+                    });
+                }
+            }
+            Tips::<T>::remove(author, tweet_id);
+            AssetTips::<T>::remove(author, tweet_id);
+            PinnedTweets::<T>::mutate_exists(author, |pinned| {
+                if let Some(ids) = pinned {
+                    ids.retain(|id| *id != tweet_id);
+                    if ids.is_empty() {
+                        *pinned = None;
+                    }
+                }
+            });
+            let tweet = (author.clone(), tweet_id);
+            if BookmarkedBy::<T>::iter_key_prefix(&tweet).next().is_some() {
+                StaleBookmarks::<T>::insert(tweet, ());
+            }
+        }
 
-            // Move all tweets to new name
-            let tweet_count = TweetCount::<T>::get(&name);
+        /// Rebind `name` from account `from` to account `to`, which has no name. Tweets, lists
+        /// and judgements are keyed by name and stay as they are; the reports, hiding, appeals,
+        /// polls, tip totals and pins of the tweets move to `to` along with the votes `name` cast,
+        /// and a suspension of `from` applies to `to` as well. Bookmarks of the tweets are queued
+        /// for removal in `on_idle`, as they point at `from`. Device keys, the avatar and
+        /// scheduled tweets belong to `from` and are dropped, and `from` gets its expiry
+        /// deposits back.
+        ///
+        /// Blocks, mutes, the messaging key and direct messages stay with `from`. `to` starts
+        /// without any, and messages sent to `name` from now on go to `to` once it registers a
+        /// messaging key, so earlier conversations are never handed to someone else. Returns the
+        /// number of tweets of `name`.
+        fn move_name(
+            name: &BoundedVec<u8, ConstU32<256>>,
+            from: &T::AccountId,
+            to: &T::AccountId,
+        ) -> u32 {
+            Names::<T>::remove(from);
+            Names::<T>::insert(to, name.clone());
+            AccountByName::<T>::insert(name, to.clone());
+
+            let tweet_count = TweetCount::<T>::get(name);
             for tweet_id in 0..tweet_count {
-                if let Some(mut tweet) = Tweets::<T>::take(&name, tweet_id) {
-                    tweet.name = new_name.clone();
-                    Tweets::<T>::insert(&new_name, tweet_id, tweet);
+                if let Some(tweet) = Tweets::<T>::get(name, tweet_id) {
+                    Self::move_tweet(from, to, tweet_id, tweet.expires_at);
                 }
             }
+            let pinned = PinnedTweets::<T>::take(from);
+            if !pinned.is_empty() {
+                PinnedTweets::<T>::insert(to, pinned);
+            }
 
-            // Update tweet count
-            TweetCount::<T>::remove(&name);
-            TweetCount::<T>::insert(&new_name, tweet_count);
+            // Moving a name must not get anyone out of a suspension
+            if let Some(suspension) = Suspended::<T>::get(from) {
+                Suspended::<T>::insert(to, suspension);
+            }
+            if let Some(statement) = Appeals::<T>::take(from, AppealSubject::Suspension) {
+                Appeals::<T>::insert(to, AppealSubject::Suspension, statement);
+            }
+            // Votes are cast by the name, not the account holding it
+            if let Some(voter) = VoterIds::<T>::take(from) {
+                VoterIds::<T>::insert(to, voter);
+            }
 
-            Self::deposit_event(Event::NameStored { name: new_name.clone(), who });
-            Self::deposit_event(Event::PasswordHashed { name: new_name.clone() });
-            
+            Avatars::<T>::remove(from);
+            let _ = Devices::<T>::clear_prefix(from, T::MaxDevices::get(), None);
+            DeviceCount::<T>::remove(from);
+            Self::cancel_scheduled_tweets(from);
 
-            Ok(())
+            tweet_count
         }
 
-        #[pallet::call_index(6)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::update_password())]
-        pub fn update_password(
-            origin: OriginFor<T>,
-            name: BoundedVec<u8, ConstU32<256>>,
-            old_password: BoundedVec<u8, ConstU32<256>>,
-            new_password: BoundedVec<u8, ConstU32<256>>,
+        /// Ensure `name`, registered to `from`, can be handed to `to`: neither is suspended,
+        /// `to` has no name yet and nobody is recovering `name`.
+        fn ensure_transferable(
+            name: &BoundedVec<u8, ConstU32<256>>,
+            from: &T::AccountId,
+            to: &T::AccountId,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-
-            let account = AccountByName::<T>::get(&name)
-                .ok_or(Error::<T>::UserNotFound)?;
-            ensure!(account == who, Error::<T>::NotAuthorized);
+            ensure!(from != to, Error::<T>::CannotTargetSelf);
+            ensure!(!Names::<T>::contains_key(to), Error::<T>::AccountHasName);
+            Self::ensure_not_suspended(from)?;
+            Self::ensure_not_suspended(to)?;
+            Self::ensure_no_active_recovery(name)
+        }
 
-            let stored_hash = PasswordHash::<T>::get(&name, 0)
-                .ok_or(Error::<T>::UserNotFound)?;
+        /// Rebind `name` from `from` to `to` for `transfer_name` and `buy_name`, see
+        /// `move_name`. Unlike a recovery this hands the name to someone else, so its recovery
+        /// config, registrar judgements and sale listing are dropped. The pallet keeps no
+        /// followers; lists are made of accounts, so lists `from` is in keep `from`. Returns the
+        /// number of tweets of `name`.
+        fn hand_over_name(
+            name: &BoundedVec<u8, ConstU32<256>>,
+            from: &T::AccountId,
+            to: &T::AccountId,
+        ) -> u32 {
+            Recoveries::<T>::remove(name);
+            Judgements::<T>::remove(name);
+            NamesForSale::<T>::remove(name);
+            Self::move_name(name, from, to)
+        }
 
-            let old_hash = blake2_256(&old_password[..]);
-            ensure!(old_hash == stored_hash, Error::<T>::InvalidCredentials);
+        /// Move what is kept about tweet `tweet_id` per author account from `from` to `to`,
+        /// see `move_name`.
+        fn move_tweet(
+            from: &T::AccountId,
+            to: &T::AccountId,
+            tweet_id: u32,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) {
+            if let Some(reports) = Reports::<T>::take(from, tweet_id) {
+                Reports::<T>::insert(to, tweet_id, reports);
+            }
+            if HiddenTweets::<T>::take(from, tweet_id).is_some() {
+                HiddenTweets::<T>::insert(to, tweet_id, ());
+            }
+            if let Some(statement) = Appeals::<T>::take(from, AppealSubject::Tweet(tweet_id)) {
+                Appeals::<T>::insert(to, AppealSubject::Tweet(tweet_id), statement);
+            }
+            if let Some(poll) = Polls::<T>::take(from, tweet_id) {
+                if !poll.closed {
+                    PollsEndingAt::<T>::mutate(poll.ends_at, |ending| {
+                        Self::rekey(ending, from, to, tweet_id)
+                    });
+                }
+                Polls::<T>::insert(to, tweet_id, poll);
+            }
+            let tips = Tips::<T>::take(from, tweet_id);
+            if !tips.is_zero() {
+                Tips::<T>::insert(to, tweet_id, tips);
+            }
+            let asset_tips = AssetTips::<T>::take(from, tweet_id);
+            if !asset_tips.is_empty() {
+                AssetTips::<T>::insert(to, tweet_id, asset_tips);
+            }
+            if let Some(at) = expires_at {
+                ExpiringAt::<T>::mutate(at, |expiring| Self::rekey(expiring, from, to, tweet_id));
+            }
+            // `from` paid for the expiry, so they get the deposit back now
+            Self::release_expiry_deposit(from, tweet_id);
+            let tweet = (from.clone(), tweet_id);
+            if BookmarkedBy::<T>::iter_key_prefix(&tweet).next().is_some() {
+                StaleBookmarks::<T>::insert(tweet, ());
+            }
+        }
 
-            let new_hash = blake2_256(&new_password[..]);
-            PasswordHash::<T>::insert(&name, 0, new_hash);
+        /// Release the deposit `author` holds for expiring tweet `tweet_id`, if any.
+        fn release_expiry_deposit(author: &T::AccountId, tweet_id: u32) {
+            if let Some(deposit) = ExpiryDeposits::<T>::take(author, tweet_id) {
+                // Best effort, so nothing is left to fail
+                let _ = T::Currency::release(
+                    &HoldReason::ExpiringTweetDeposit.into(),
+                    author,
+                    deposit,
+                    Precision::BestEffort,
+                );
+            }
+        }
 
-            Self::deposit_event(Event::PasswordHashed { name });
+        /// Point the `(from, tweet_id)` entry of a per block queue at `to` instead.
+        fn rekey<S>(
+            entries: &mut BoundedVec<(T::AccountId, u32), S>,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            tweet_id: u32,
+        ) {
+            for entry in entries.iter_mut().filter(|(a, id)| (a, *id) == (from, tweet_id)) {
+                entry.0 = to.clone();
+            }
+        }
 
+        /// Ensure no recovery of `name` that a friend vouched for is in progress.
+        fn ensure_no_active_recovery(name: &BoundedVec<u8, ConstU32<256>>) -> DispatchResult {
+            ensure!(VouchedRecoveries::<T>::get(name) == 0, Error::<T>::RecoveryInProgress);
             Ok(())
         }
 
-        #[pallet::call_index(7)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::delete_tweet())]
-        pub fn delete_tweet(
-            origin: OriginFor<T>,
-            name: BoundedVec<u8, ConstU32<256>>,
-            password: BoundedVec<u8, ConstU32<256>>,
+        /// Stop counting `recovery` of `name`, which was just claimed or cancelled, towards
+        /// the vouched recoveries of `name`.
+        fn forget_vouched_recovery(
+            name: &BoundedVec<u8, ConstU32<256>>,
+            recovery: &ActiveRecovery<T>,
+        ) {
+            if !recovery.vouchers.is_empty() {
+                VouchedRecoveries::<T>::mutate_exists(name, |count| {
+                    *count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0)
+                });
+            }
+        }
+
+        /// The account registered as `name` and whether its tweet `tweet_id` exists.
+        fn tweet_author(
+            name: &BoundedVec<u8, ConstU32<256>>,
             tweet_id: u32,
-        ) -> DispatchResult {
+        ) -> Result<T::AccountId, DispatchError> {
+            let author = AccountByName::<T>::get(name).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(Tweets::<T>::contains_key(name, tweet_id), Error::<T>::TweetNotFound);
+            Ok(author)
+        }
 
-            let who = ensure_signed(origin)?;
+        /// Ensure `name` is registered to `who` and `password` is its password.
+        fn authenticate(
+            who: &T::AccountId,
+            name: &BoundedVec<u8, ConstU32<256>>,
+            password: &BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let account = AccountByName::<T>::get(name).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(&account == who, Error::<T>::NotAuthorized);
 
-            // Verify the user exists and owns this name
-            let account = AccountByName::<T>::get(&name)
-                .ok_or(Error::<T>::UserNotFound)?;
-            ensure!(account == who, Error::<T>::NotAuthorized);
+            let stored_hash = PasswordHash::<T>::get(name, 0).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(blake2_256(&password[..]) == stored_hash, Error::<T>::InvalidCredentials);
+            Ok(())
+        }
 
-            // Verify password
-            let stored_hash = PasswordHash::<T>::get(&name, 0)
-                .ok_or(Error::<T>::UserNotFound)?;
-            let password_hash = blake2_256(&password[..]);
-            ensure!(password_hash == stored_hash, Error::<T>::InvalidCredentials);
+        /// Like `authenticate`, but `who` can also be a device of the account registered as
+        /// `name` that has `scope`. Returns the account registered as `name`, which the call
+        /// acts for.
+        fn authorize(
+            who: &T::AccountId,
+            name: &BoundedVec<u8, ConstU32<256>>,
+            password: &BoundedVec<u8, ConstU32<256>>,
+            scope: DeviceScope,
+        ) -> Result<T::AccountId, DispatchError> {
+            let account = AccountByName::<T>::get(name).ok_or(Error::<T>::UserNotFound)?;
+            Self::ensure_acts_for(who, &account, scope)?;
+
+            let stored_hash = PasswordHash::<T>::get(name, 0).ok_or(Error::<T>::UserNotFound)?;
+            ensure!(blake2_256(&password[..]) == stored_hash, Error::<T>::InvalidCredentials);
+            Ok(account)
+        }
 
-            // Ensure tweet exists
-            ensure!(Tweets::<T>::contains_key(&name, tweet_id), Error::<T>::TweetNotFound);
+        /// Ensure `who` is `account` or one of its devices with `scope` that hasn't expired.
+        fn ensure_acts_for(
+            who: &T::AccountId,
+            account: &T::AccountId,
+            scope: DeviceScope,
+        ) -> DispatchResult {
+            if who == account {
+                return Ok(());
+            }
+            let device = Devices::<T>::get(account, who).ok_or(Error::<T>::NotAuthorized)?;
+            ensure!(device.scopes.contains(&scope), Error::<T>::MissingDeviceScope);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(device.expires_at.is_none_or(|at| now < at), Error::<T>::DeviceExpired);
+            Ok(())
+        }
 
-            // Remove the tweet
-            Tweets::<T>::remove(&name, tweet_id);
+        /// The voter ID of the name registered to `who`, assigning one if it never voted.
+        fn voter_id(who: &T::AccountId) -> u64 {
+            VoterIds::<T>::get(who).unwrap_or_else(|| {
+                let id = NextVoterId::<T>::mutate(|next| {
+                    let id = *next;
+                    next.saturating_inc();
+                    id
+                });
+                VoterIds::<T>::insert(who, id);
+                id
+            })
+        }
 
+        /// Ensure `owner` has not blocked `who`.
+        ///
+        /// Every call that lets `who` interact with someone else's account goes through this,
+        /// directly or through [`Self::ensure_not_blocked_by_name`].
+        pub fn ensure_not_blocked(who: &T::AccountId, owner: &T::AccountId) -> DispatchResult {
+            ensure!(!Blocked::<T>::contains_key(owner, who), Error::<T>::Blocked);
             Ok(())
+        }
 
+        /// Ensure the user registered as `name`, if any, has not blocked `who`.
+        pub fn ensure_not_blocked_by_name(who: &T::AccountId, name: &[u8]) -> DispatchResult {
+            let Ok(name) = BoundedVec::<u8, ConstU32<256>>::try_from(name.to_vec()) else {
+                // Too long to be anyone's name
+                return Ok(());
+            };
+            match AccountByName::<T>::get(&name) {
+                Some(account) => Self::ensure_not_blocked(who, &account),
+                None => Ok(()),
+            }
         }
 
-        #[pallet::call_index(8)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::delete_user())]
-        pub fn delete_user(
-            origin: OriginFor<T>,
-            name: BoundedVec<u8, ConstU32<256>>,
-            password: BoundedVec<u8, ConstU32<256>>,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+        /// The names mentioned in `content` as `@name`, without trailing punctuation.
+        pub fn mentions(content: &[u8]) -> impl Iterator<Item = &[u8]> {
+            content
+                .split(|byte| byte.is_ascii_whitespace())
+                .filter_map(|word| word.strip_prefix(b"@"))
+                .map(|name| {
+                    let end = name
+                        .iter()
+                        .rposition(|byte| !b".,:;!?)".contains(byte))
+                        .map_or(0, |last| last + 1);
+                    &name[..end]
+                })
+                .filter(|name| !name.is_empty())
+        }
 
-            // Get account by name
-            let account = AccountByName::<T>::get(&name)
-                .ok_or(Error::<T>::UserNotFound)?;
-            
-            // Ensure the caller owns this account
-            ensure!(account == who, Error::<T>::NotAuthorized);
+        /// The profile of the user registered as `name`, for the runtime API.
+        pub fn profile(
+            name: &[u8],
+        ) -> Option<Profile<T::AccountId, T::NftCollectionId, T::NftId>> {
+            let name = BoundedVec::<u8, ConstU32<256>>::try_from(name.to_vec()).ok()?;
+            let account = AccountByName::<T>::get(&name)?;
+            let registrars = Registrars::<T>::get();
+            let judgements = Judgements::<T>::get(&name)
+                .into_iter()
+                .filter(|(registrar, _)| registrars.contains(registrar))
+                .collect();
+
+            Some(Profile {
+                identity: T::Identity::judgement(&account),
+                messaging_key: MessagingKeys::<T>::get(&account),
+                avatar: Avatars::<T>::get(&account).filter(|(collection, item)| {
+                    T::Nfts::owner(collection, item).as_ref() == Some(&account)
+                }),
+                account,
+                birthday: Birthdays::<T>::get(&name, 0),
+                tweet_count: TweetCount::<T>::get(&name),
+                judgements,
+                name,
+            })
+        }
 
-            // Verify password
-            let stored_hash = PasswordHash::<T>::get(&name, 0)
-                .ok_or(Error::<T>::UserNotFound)?;
-            let password_hash = blake2_256(&password[..]);
-            ensure!(password_hash == stored_hash, Error::<T>::InvalidCredentials);
+        /// Tweet `tweet_id` of `author`, unless it is gone or hidden by a moderator.
+        fn visible_tweet(author: &T::AccountId, tweet_id: u32) -> Option<Tweet<BlockNumberFor<T>>> {
+            if HiddenTweets::<T>::contains_key(author, tweet_id) {
+                return None;
+            }
+            Tweets::<T>::get(Names::<T>::get(author)?, tweet_id)
+        }
 
-            // Remove name mappings
-            Names::<T>::remove(&account);
-            AccountByName::<T>::remove(&name);
+        /// The visible tweets the user registered as `name` pinned with their IDs, for the
+        /// runtime API.
+        pub fn pinned_tweets(name: &[u8]) -> Vec<(u32, Tweet<BlockNumberFor<T>>)> {
+            let Some(account) = BoundedVec::<u8, ConstU32<256>>::try_from(name.to_vec())
+                .ok()
+                .and_then(AccountByName::<T>::get)
+            else {
+                return Vec::new();
+            };
+            PinnedTweets::<T>::get(&account)
+                .into_iter()
+                .filter_map(|id| Self::visible_tweet(&account, id).map(|tweet| (id, tweet)))
+                .collect()
+        }
 
-            // Remove birthday and password
-            Birthdays::<T>::remove(&name, 0);
-            PasswordHash::<T>::remove(&name, 0);
+        /// The visible tweets `who` bookmarked with their IDs, oldest bookmark first, for the
+        /// runtime API. The author's name is part of the tweet.
+        pub fn bookmarks(who: &T::AccountId) -> Vec<(u32, Tweet<BlockNumberFor<T>>)> {
+            Bookmarks::<T>::get(who)
+                .into_iter()
+                .filter_map(|(author, id)| Self::visible_tweet(&author, id).map(|tweet| (id, tweet)))
+                .collect()
+        }
 
-            // Remove all tweets
-            let tweet_count = TweetCount::<T>::get(&name);
-            for tweet_id in 0..tweet_count {
-                Tweets::<T>::remove(&name, tweet_id);
+        /// The most recent visible tweets of the members of list `list` of `owner`, newest first,
+        /// for the runtime API. Private lists are only returned when `viewer` is their owner.
+        pub fn list_tweets(
+            owner: &[u8],
+            list: &[u8],
+            viewer: Option<T::AccountId>,
+            limit: u32,
+        ) -> Vec<(u32, Tweet<BlockNumberFor<T>>)> {
+            let (Ok(owner), Ok(list)) = (
+                BoundedVec::<u8, ConstU32<256>>::try_from(owner.to_vec()),
+                BoundedVec::<u8, ConstU32<64>>::try_from(list.to_vec()),
+            ) else {
+                return Vec::new();
+            };
+            let Some(user_list) = Lists::<T>::get(&owner, &list) else {
+                return Vec::new();
+            };
+            if user_list.private && viewer != AccountByName::<T>::get(&owner) {
+                return Vec::new();
             }
-            TweetCount::<T>::remove(&name);
 
+            let limit = limit.min(T::MaxTweetsPerUser::get()) as usize;
+            let mut tweets = Vec::new();
+            for member in user_list.members {
+                let Some(name) = Names::<T>::get(&member) else {
+                    continue;
+                };
+                // Each member contributes at most `limit` tweets, their newest ones
+                let recent = (0..TweetCount::<T>::get(&name))
+                    .rev()
+                    .filter_map(|id| Self::visible_tweet(&member, id).map(|tweet| (id, tweet)))
+                    .take(limit);
+                tweets.extend(recent);
+            }
+            tweets.sort_by_key(|(_, tweet)| core::cmp::Reverse(tweet.timestamp));
+            tweets.truncate(limit);
+            tweets
+        }
+
+        /// The poll on tweet `tweet_id` of the user registered as `author`, for the runtime API.
+        pub fn poll(author: &[u8], tweet_id: u32) -> Option<Poll<BlockNumberFor<T>>> {
+            let author = BoundedVec::<u8, ConstU32<256>>::try_from(author.to_vec()).ok()?;
+            Polls::<T>::get(AccountByName::<T>::get(&author)?, tweet_id)
+        }
 
-            Ok(())
+        /// Total amount tipped to tweet `tweet_id` of the user registered as `author`, for the
+        /// runtime API.
+        pub fn tips(author: &[u8], tweet_id: u32) -> BalanceOf<T> {
+            BoundedVec::<u8, ConstU32<256>>::try_from(author.to_vec())
+                .ok()
+                .and_then(AccountByName::<T>::get)
+                .map(|author| Tips::<T>::get(author, tweet_id))
+                .unwrap_or_default()
+        }
+
+        /// Total amounts tipped to tweet `tweet_id` of the user registered as `author` per asset,
+        /// for the runtime API.
+        pub fn asset_tips(author: &[u8], tweet_id: u32) -> Vec<(AssetIdOf<T>, AssetBalanceOf<T>)> {
+            BoundedVec::<u8, ConstU32<256>>::try_from(author.to_vec())
+                .ok()
+                .and_then(AccountByName::<T>::get)
+                .map(|author| AssetTips::<T>::get(author, tweet_id).into_inner())
+                .unwrap_or_default()
+        }
+
+        /// Storage key of the conversation between `a` and `b`, the same whoever sends.
+        fn conversation(a: &T::AccountId, b: &T::AccountId) -> (T::AccountId, T::AccountId) {
+            if a < b {
+                (a.clone(), b.clone())
+            } else {
+                (b.clone(), a.clone())
+            }
+        }
+
+        /// Up to `limit` messages between the users registered as `name` and `with`, starting
+        /// at message ID `start`, for the runtime API. Continue from the last returned ID plus
+        /// one to get the next page.
+        #[allow(clippy::type_complexity)]
+        pub fn messages(
+            name: &[u8],
+            with: &[u8],
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, Message<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>)> {
+            let account = |name: &[u8]| {
+                BoundedVec::<u8, ConstU32<256>>::try_from(name.to_vec())
+                    .ok()
+                    .and_then(AccountByName::<T>::get)
+            };
+            let (Some(a), Some(b)) = (account(name), account(with)) else {
+                return Vec::new();
+            };
+
+            let conversation = Self::conversation(&a, &b);
+            let (next_id, _) = MessageCount::<T>::get(&conversation);
+            (start..next_id)
+                .filter_map(|id| Messages::<T>::get(&conversation, id).map(|message| (id, message)))
+                .take(limit.min(T::MaxMessagesPerConversation::get()) as usize)
+                .collect()
         }
 
+        /// Names muted by the user registered as `name`, for the runtime API.
+        pub fn muted_names(name: &[u8]) -> Vec<Vec<u8>> {
+            let Some(account) = BoundedVec::<u8, ConstU32<256>>::try_from(name.to_vec())
+                .ok()
+                .and_then(AccountByName::<T>::get)
+            else {
+                return Vec::new();
+            };
+            Muted::<T>::iter_key_prefix(&account)
+                .filter_map(Names::<T>::get)
+                .map(Into::into)
+                .collect()
+        }
     }
 }
\ No newline at end of file
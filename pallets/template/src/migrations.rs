@@ -0,0 +1,72 @@
+//! Storage migrations of the template pallet.
+
+use crate::{Config, Pallet, Tweet, Tweets};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+    migrations::VersionedMigration,
+    pallet_prelude::*,
+    traits::{ConstU32, UncheckedOnRuntimeUpgrade},
+};
+use sp_runtime::Saturating;
+#[cfg(feature = "try-runtime")]
+use {alloc::vec::Vec, sp_runtime::TryRuntimeError};
+
+/// Version 1 adds an `expires_at` block to tweets.
+pub mod v1 {
+    use super::*;
+
+    /// A tweet as stored up to version 0.
+    #[derive(Encode, Decode)]
+    pub struct OldTweet {
+        pub name: BoundedVec<u8, ConstU32<256>>,
+        pub content: BoundedVec<u8, ConstU32<280>>,
+        pub timestamp: u64,
+    }
+
+    /// Rewrites every stored tweet as one that never expires. Use [`MigrateV0ToV1`] instead,
+    /// which only runs this on storage version 0.
+    pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Tweets::<T>::translate::<OldTweet, _>(|_, _, old| {
+                translated.saturating_inc();
+                Some(Tweet {
+                    name: old.name,
+                    content: old.content,
+                    timestamp: old.timestamp,
+                    expires_at: None,
+                })
+            });
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((Tweets::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let before = u64::decode(&mut &state[..])
+                .map_err(|_| "the tweet count from pre_upgrade decodes")?;
+            // `iter` decodes every value, so this also checks that all tweets were translated.
+            ensure!(
+                Tweets::<T>::iter().count() as u64 == before,
+                "the migration keeps every tweet"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Migrates tweets to storage version 1 if the pallet is on version 0, and bumps the version.
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    v1::UncheckedMigrateToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
@@ -1,5 +1,12 @@
 use crate as pallet_template;
-use frame_support::derive_impl;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{
+        tokens::nonfungibles_v2, AsEnsureOriginWithArg, ConstU32, ConstU64, EqualPrivilegeOnly,
+    },
+    weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
 use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -10,6 +17,10 @@ frame_support::construct_runtime!(
     {
         System: frame_system,
         Timestamp: pallet_timestamp,
+        Balances: pallet_balances,
+        Preimage: pallet_preimage,
+        Scheduler: pallet_scheduler,
+        Assets: pallet_assets,
         TemplateModule: pallet_template,
     }
 );
@@ -17,6 +28,46 @@ frame_support::construct_runtime!(
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
     type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+    type RuntimeHoldReason = RuntimeHoldReason;
+}
+
+impl pallet_preimage::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type Currency = Balances;
+    type ManagerOrigin = EnsureRoot<u64>;
+    type Consideration = ();
+}
+
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight = Weight::from_parts(1_000_000_000_000, u64::MAX);
+}
+
+impl pallet_scheduler::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = EnsureRoot<u64>;
+    type OriginPrivilegeCmp = EqualPrivilegeOnly;
+    type MaxScheduledPerBlock = ConstU32<10>;
+    type WeightInfo = ();
+    type Preimages = Preimage;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+    type ForceOrigin = EnsureRoot<u64>;
+    type Freezer = ();
 }
 
 #[derive_impl(pallet_timestamp::config_preludes::TestDefaultConfig)]
@@ -24,12 +75,103 @@ impl pallet_timestamp::Config for Test {
     type Moment = u64;
 }
 
+parameter_types! {
+    // Identity judgements per account, standing in for `pallet_identity`
+    pub static IdentityJudgements: Vec<(u64, pallet_template::Judgement)> = vec![];
+    pub static RequireIdentity: bool = false;
+}
+
+pub struct MockIdentity;
+impl pallet_template::IdentityJudgement<u64> for MockIdentity {
+    fn judgement(who: &u64) -> Option<pallet_template::Judgement> {
+        IdentityJudgements::get()
+            .into_iter()
+            .find_map(|(account, judgement)| (account == *who).then_some(judgement))
+    }
+}
+
+parameter_types! {
+    // Owners of NFTs by collection and item, standing in for `pallet_nfts`
+    pub static NftOwners: Vec<((u32, u32), u64)> = vec![];
+}
+
+pub struct MockNfts;
+impl nonfungibles_v2::Inspect<u64> for MockNfts {
+    type CollectionId = u32;
+    type ItemId = u32;
+
+    fn owner(collection: &u32, item: &u32) -> Option<u64> {
+        NftOwners::get()
+            .into_iter()
+            .find_map(|(nft, owner)| (nft == (*collection, *item)).then_some(owner))
+    }
+}
+
+/// Give item `item` of collection `collection` to `owner`, minting it if it doesn't exist.
+pub fn give_nft(collection: u32, item: u32, owner: u64) {
+    NftOwners::mutate(|owners| {
+        owners.retain(|(nft, _)| *nft != (collection, item));
+        owners.push(((collection, item), owner));
+    });
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_template::BenchmarkHelper<u64, u32, u32> for MockNfts {
+    fn mint_nft(owner: &u64) -> (u32, u32) {
+        give_nft(0, 0, *owner);
+        (0, 0)
+    }
+}
+
 impl pallet_template::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type MessageDeposit = ConstU64<10>;
+    type MaxMessagesPerConversation = ConstU32<3>;
+    type MaxPollsEndingPerBlock = ConstU32<2>;
+    type MaxPollDuration = ConstU64<100>;
+    type RuntimeCall = RuntimeCall;
+    type PalletsOrigin = OriginCaller;
+    type Scheduler = Scheduler;
+    type MaxScheduledTweets = ConstU32<2>;
+    type MaxScheduledTweetsPerBlock = ConstU32<1>;
+    type MaxExpiringPerBlock = ConstU32<2>;
+    type ExpiringTweetDeposit = ConstU64<5>;
+    type MaxPinnedTweets = ConstU32<2>;
+    type MaxBookmarks = ConstU32<2>;
+    type MaxListsPerUser = ConstU32<2>;
+    type MaxListMembers = ConstU32<2>;
+    type MaxDevices = ConstU32<2>;
+    type MaxFriends = ConstU32<3>;
+    type RecoveryDeposit = ConstU64<10>;
+    type Assets = Assets;
+    type MaxTipAssets = ConstU32<2>;
+    type NftCollectionId = u32;
+    type NftId = u32;
+    type Nfts = MockNfts;
+    type MaxAvatarChecksPerBlock = ConstU32<2>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockNfts;
+    type MaxTweetsPerUser = ConstU32<10>;
+    type MaxPostsPerPeriod = ConstU32<3>;
+    type QuotaPeriod = ConstU64<10>;
+    type PostLongevity = ConstU64<32>;
+    type MaxMentions = ConstU32<5>;
+    type ModeratorOrigin = EnsureRoot<u64>;
+    type MaxReportsPerTweet = ConstU32<3>;
+    type AdminOrigin = EnsureRoot<u64>;
+    type MaxRegistrars = ConstU32<2>;
+    type Identity = MockIdentity;
+    type RequireIdentity = RequireIdentity;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> { balances: (1..=5).map(|who| (who, 100)).collect() }
+        .assimilate_storage(&mut storage)
+        .unwrap();
+    storage.into()
 }
\ No newline at end of file
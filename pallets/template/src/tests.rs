@@ -1,7 +1,7 @@
 use crate::{mock::*, Error, Event};
 
     use frame_support::{assert_noop, assert_ok, BoundedVec};
-	use frame_support::traits::ConstU32;
+	use frame_support::traits::{ConstU32, Get};
 
     // Helper function to create a bounded vec from a string
 	fn bounded_vec(s: &str) -> BoundedVec<u8, ConstU32<256>> {
@@ -231,3 +231,1441 @@ fn delete_tweet_test() {
 }
 
 
+
+#[test]
+fn genesis_config_registers_users_and_tweets() {
+	use sp_runtime::BuildStorage;
+
+	let storage = RuntimeGenesisConfig {
+		template_module: crate::GenesisConfig {
+			users: vec![(1, b"Alice".to_vec(), b"123".to_vec(), (1990, 5, 4))],
+			tweets: vec![(b"Alice".to_vec(), b"gm".to_vec())],
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		assert_eq!(crate::AccountByName::<Test>::get(bounded_vec("Alice")), Some(1));
+		assert_eq!(crate::TweetCount::<Test>::get(bounded_vec("Alice")), 1);
+		assert_eq!(
+			crate::Tweets::<Test>::get(bounded_vec("Alice"), 0).map(|t| t.content),
+			Some(bounded_tweet("gm"))
+		);
+
+		assert_ok!(TemplateModule::verify_user(
+			RuntimeOrigin::signed(1),
+			bounded_vec("Alice"),
+			bounded_vec("123")));
+	})
+}
+
+#[test]
+fn create_tweet_fails_past_max_tweets_per_user() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::create_user(
+			RuntimeOrigin::signed(1),
+			bounded_vec("Alice"),
+			bounded_vec("123"),
+			1990, 5, 4 ));
+
+		for _ in 0..<Test as crate::Config>::MaxTweetsPerUser::get() {
+			assert_ok!(TemplateModule::create_tweet(
+				RuntimeOrigin::signed(1),
+				bounded_vec("Alice"),
+				bounded_vec("123"),
+				bounded_tweet("gm")));
+		}
+
+		assert_noop!(
+			TemplateModule::create_tweet(
+				RuntimeOrigin::signed(1),
+				bounded_vec("Alice"),
+				bounded_vec("123"),
+				bounded_tweet("one too many")
+			),
+			Error::<Test>::TooManyTweets
+		);
+	})
+}
+
+#[test]
+fn tweet_walking_calls_only_charge_for_existing_tweets() {
+	use crate::WeightInfo;
+
+	new_test_ext().execute_with(|| {
+		let max_tweets = <Test as crate::Config>::MaxTweetsPerUser::get();
+
+		assert_ok!(TemplateModule::create_user(
+			RuntimeOrigin::signed(1),
+			bounded_vec("Alice"),
+			bounded_vec("123"),
+			1990, 5, 4 ));
+		assert_ok!(TemplateModule::create_tweet(
+			RuntimeOrigin::signed(1),
+			bounded_vec("Alice"),
+			bounded_vec("123"),
+			bounded_tweet("gm")));
+
+		let post_info = TemplateModule::update_user_name(
+			RuntimeOrigin::signed(1),
+			bounded_vec("Alice"),
+			bounded_vec("123"),
+			bounded_vec("Alicia"),
+		)
+		.unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::update_user_name(6, 3, 1)));
+		assert!(
+			post_info.actual_weight.unwrap().ref_time() <
+				<() as WeightInfo>::update_user_name(6, 3, max_tweets).ref_time()
+		);
+		// The birthday goes along with the name.
+		assert!(TemplateModule::profile(b"Alicia").unwrap().birthday.is_some());
+		assert!(TemplateModule::profile(b"Alice").is_none());
+
+		let post_info = TemplateModule::delete_user(
+			RuntimeOrigin::signed(1),
+			bounded_vec("Alicia"),
+			bounded_vec("123"),
+		)
+		.unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::delete_user(6, 3, 1)));
+		assert!(
+			post_info.actual_weight.unwrap().ref_time() <
+				<() as WeightInfo>::delete_user(6, 3, max_tweets).ref_time()
+		);
+	})
+}
+
+#[test]
+fn registered_users_post_feeless_within_their_quota() {
+	use crate::{FeelessPosting, POST_QUOTA_EXHAUSTED};
+	use frame_support::dispatch::DispatchInfo;
+	use frame_system::CheckWeight;
+	use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction};
+
+	let extension = || FeelessPosting::<Test, _>::from(CheckWeight::<Test>::new());
+	let tweet: RuntimeCall = crate::Call::create_tweet {
+		name: bounded_vec("Alice"),
+		password: bounded_vec("123"),
+		content: bounded_tweet("gm"),
+	}
+	.into();
+	let info = DispatchInfo::default();
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Accounts without a name pay through the wrapped extension and have no quota.
+		assert_eq!(extension().pre_dispatch(&1, &tweet, &info, 0), Ok(Some(())));
+		assert_eq!(crate::PostQuota::<Test>::get(1), None);
+
+		assert_ok!(TemplateModule::create_user(
+			RuntimeOrigin::signed(1),
+			bounded_vec("Alice"),
+			bounded_vec("123"),
+			1990, 5, 4 ));
+
+		// Only posting is feeless, calls that walk over every tweet of a user still pay.
+		let rename: RuntimeCall = crate::Call::update_user_name {
+			name: bounded_vec("Alice"),
+			password: bounded_vec("123"),
+			new_name: bounded_vec("Alicia"),
+		}
+		.into();
+		assert_eq!(extension().pre_dispatch(&1, &rename, &info, 0), Ok(Some(())));
+		assert_eq!(crate::PostQuota::<Test>::get(1), None);
+
+		// Feeless calls compete for the next quota slot, so only one of them is in the pool at a
+		// time, and the next one can take the following slot once it is in a block.
+		let first = extension().validate(&1, &tweet, &info, 0).unwrap();
+		assert_eq!(extension().validate(&1, &tweet, &info, 0).unwrap().provides, first.provides);
+
+		for _ in 0..<Test as crate::Config>::MaxPostsPerPeriod::get() {
+			assert_eq!(extension().pre_dispatch(&1, &tweet, &info, 0), Ok(None));
+			if let Ok(next) = extension().validate(&1, &tweet, &info, 0) {
+				assert_ne!(next.provides, first.provides);
+			}
+		}
+		assert_eq!(crate::PostQuota::<Test>::get(1), Some((1, 3)));
+		assert_eq!(
+			extension().validate(&1, &tweet, &info, 0),
+			Err(InvalidTransaction::Custom(POST_QUOTA_EXHAUSTED).into())
+		);
+
+		// A new window starts once the quota period is over.
+		System::set_block_number(11);
+		assert_ok!(extension().validate(&1, &tweet, &info, 0));
+		assert_eq!(extension().pre_dispatch(&1, &tweet, &info, 0), Ok(None));
+		assert_eq!(crate::PostQuota::<Test>::get(1), Some((11, 1)));
+	})
+}
+
+#[test]
+fn template_calls_get_pool_priority_tags_and_longevity() {
+	use crate::{FeelessPosting, BULK_PRIORITY, POST_PRIORITY};
+	use frame_support::dispatch::DispatchInfo;
+	use frame_system::CheckWeight;
+	use sp_runtime::traits::SignedExtension;
+
+	let extension = || FeelessPosting::<Test, _>::from(CheckWeight::<Test>::new());
+	let tweet = |content: &str| -> RuntimeCall {
+		crate::Call::create_tweet {
+			name: bounded_vec("Alice"),
+			password: bounded_vec("123"),
+			content: bounded_tweet(content),
+		}
+		.into()
+	};
+	let register: RuntimeCall = crate::Call::create_user {
+		name: bounded_vec("Alice"),
+		password: bounded_vec("123"),
+		year: 1990,
+		month: 5,
+		day: 4,
+	}
+	.into();
+	let delete: RuntimeCall =
+		crate::Call::delete_user { name: bounded_vec("Alice"), password: bounded_vec("123") }.into();
+	let info = DispatchInfo::default();
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Tweets from a name nobody registered yet wait for its registration.
+		let registration = extension().validate(&1, &register, &info, 0).unwrap();
+		let early_tweet = extension().validate(&1, &tweet("gm"), &info, 0).unwrap();
+		assert_eq!(early_tweet.requires, registration.provides);
+
+		assert_ok!(TemplateModule::create_user(
+			RuntimeOrigin::signed(1),
+			bounded_vec("Alice"),
+			bounded_vec("123"),
+			1990, 5, 4 ));
+
+		// Identical tweets provide the same tag, so only one of them makes it into the pool.
+		let first = extension().validate(&1, &tweet("gm"), &info, 0).unwrap();
+		let again = extension().validate(&1, &tweet("gm"), &info, 0).unwrap();
+		let other = extension().validate(&1, &tweet("gn"), &info, 0).unwrap();
+		assert!(first.requires.is_empty());
+		assert_eq!(first.provides, again.provides);
+		assert_ne!(first.provides, other.provides);
+		assert_eq!(first.priority, POST_PRIORITY);
+		assert_eq!(first.longevity, 32);
+
+		// Someone else sending the same tweet cannot keep Alice's out of the pool.
+		let copy = extension().validate(&2, &tweet("gm"), &info, 0).unwrap();
+		assert!(copy.provides.iter().all(|tag| !first.provides.contains(tag)));
+
+		// Calls that walk over every tweet of a user make way for regular posts.
+		assert_eq!(extension().validate(&1, &delete, &info, 0).unwrap().priority, BULK_PRIORITY);
+	})
+}
+
+#[test]
+fn blocked_users_cannot_mention_the_blocker() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 1991, 6, 5));
+
+		// Only the owner of a name can block on its behalf, and nobody can block themselves.
+		assert_noop!(
+			TemplateModule::block_user(RuntimeOrigin::signed(2), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Bob")),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			TemplateModule::block_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Alice")),
+			Error::<Test>::CannotTargetSelf
+		);
+
+		assert_ok!(TemplateModule::block_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Bob")));
+		System::assert_last_event(Event::UserBlocked { name: bounded_vec("Alice"), target: bounded_vec("Bob") }.into());
+
+		assert_noop!(
+			TemplateModule::create_tweet(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), bounded_tweet("Hey @Alice!")),
+			Error::<Test>::Blocked
+		);
+		// Alice can still mention Bob, and Bob can still tweet without mentioning Alice.
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("@Bob, bye")));
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), bounded_tweet("Hey Alice")));
+
+		// Renaming does not get Bob out of the block.
+		assert_ok!(TemplateModule::update_user_name(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), bounded_vec("Robert")));
+		assert_noop!(
+			TemplateModule::create_tweet(RuntimeOrigin::signed(2), bounded_vec("Robert"), bounded_vec("456"), bounded_tweet("@Alice?")),
+			Error::<Test>::Blocked
+		);
+
+		assert_ok!(TemplateModule::unblock_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Robert")));
+		assert_noop!(
+			TemplateModule::unblock_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Robert")),
+			Error::<Test>::NotBlocked
+		);
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(2), bounded_vec("Robert"), bounded_vec("456"), bounded_tweet("@Alice?")));
+	})
+}
+
+#[test]
+fn blocked_users_cannot_tip_vote_on_or_bookmark_the_blockers_tweets() {
+	use frame_support::traits::fungibles::Mutate;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 1991, 6, 5));
+		let options = BoundedVec::truncate_from(vec![BoundedVec::truncate_from(b"yes".to_vec()), BoundedVec::truncate_from(b"no".to_vec())]);
+		assert_ok!(TemplateModule::create_poll_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Tea?"), options, 20));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 10, 2, true, 1));
+		assert_ok!(<Assets as Mutate<u64>>::mint_into(10, &2, 50));
+		assert_ok!(TemplateModule::block_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Bob")));
+
+		assert_noop!(TemplateModule::tip_tweet(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, 5), Error::<Test>::Blocked);
+		assert_noop!(TemplateModule::tip_tweet_with_asset(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, 10, 5), Error::<Test>::Blocked);
+		assert_noop!(TemplateModule::vote_poll(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, 0), Error::<Test>::Blocked);
+		assert_noop!(TemplateModule::bookmark(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0), Error::<Test>::Blocked);
+
+		assert_ok!(TemplateModule::unblock_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Bob")));
+		assert_ok!(TemplateModule::tip_tweet(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, 5));
+		assert_ok!(TemplateModule::tip_tweet_with_asset(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, 10, 5));
+		assert_ok!(TemplateModule::vote_poll(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, 0));
+		assert_ok!(TemplateModule::bookmark(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0));
+	})
+}
+
+#[test]
+fn create_tweet_limits_mentions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+
+		let max_mentions: u32 = <Test as crate::Config>::MaxMentions::get();
+		let at_limit = vec!["@x"; max_mentions as usize].join(" ");
+		let past_limit = vec!["@x"; max_mentions as usize + 1].join(" ");
+
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet(&at_limit)));
+		assert_noop!(
+			TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet(&past_limit)),
+			Error::<Test>::TooManyMentions
+		);
+
+		// Trailing punctuation is not part of the mentioned name, a lone `@` mentions nobody.
+		let mentions: Vec<&[u8]> = TemplateModule::mentions(b"cc @Bob, @Carol!) @ me@x").collect();
+		assert_eq!(mentions, vec![&b"Bob"[..], &b"Carol"[..]]);
+	})
+}
+
+#[test]
+fn muted_names_follow_renames() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 1991, 6, 5));
+
+		assert_ok!(TemplateModule::mute_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Bob")));
+		System::assert_last_event(Event::UserMuted { name: bounded_vec("Alice"), target: bounded_vec("Bob") }.into());
+		assert_eq!(TemplateModule::muted_names(b"Alice"), vec![b"Bob".to_vec()]);
+
+		// Muting does not stop mentions, that is left to clients.
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), bounded_tweet("@Alice")));
+
+		assert_ok!(TemplateModule::update_user_name(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), bounded_vec("Robert")));
+		assert_eq!(TemplateModule::muted_names(b"Alice"), vec![b"Robert".to_vec()]);
+
+		assert_ok!(TemplateModule::unmute_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Robert")));
+		assert!(TemplateModule::muted_names(b"Alice").is_empty());
+		assert_noop!(
+			TemplateModule::unmute_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Robert")),
+			Error::<Test>::NotMuted
+		);
+		assert!(TemplateModule::muted_names(b"Nobody").is_empty());
+	})
+}
+
+#[test]
+fn reports_queue_up_until_a_moderator_acts() {
+	use crate::Reports;
+	use sp_runtime::DispatchError;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Buy my coin")));
+
+		assert_noop!(
+			TemplateModule::report_tweet(RuntimeOrigin::signed(2), bounded_vec("Alice"), 1, bounded_vec("spam")),
+			Error::<Test>::TweetNotFound
+		);
+		assert_noop!(
+			TemplateModule::report_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), 0, bounded_vec("spam")),
+			Error::<Test>::CannotTargetSelf
+		);
+
+		// Anyone can report a tweet once, up to `MaxReportsPerTweet` reports.
+		for reporter in 2..5 {
+			assert_ok!(TemplateModule::report_tweet(RuntimeOrigin::signed(reporter), bounded_vec("Alice"), 0, bounded_vec("spam")));
+		}
+		System::assert_last_event(Event::TweetReported { name: bounded_vec("Alice"), tweet_id: 0, reporter: 4 }.into());
+		assert_noop!(
+			TemplateModule::report_tweet(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, bounded_vec("spam")),
+			Error::<Test>::AlreadyReported
+		);
+		assert_noop!(
+			TemplateModule::report_tweet(RuntimeOrigin::signed(5), bounded_vec("Alice"), 0, bounded_vec("spam")),
+			Error::<Test>::TooManyReports
+		);
+		assert_eq!(Reports::<Test>::get(1, 0).unwrap().len(), 3);
+
+		// Only moderators work through the queue.
+		assert_noop!(
+			TemplateModule::dismiss_reports(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::dismiss_reports(RuntimeOrigin::root(), bounded_vec("Alice"), 0));
+		System::assert_last_event(Event::ReportsDismissed { name: bounded_vec("Alice"), tweet_id: 0 }.into());
+		assert!(!Reports::<Test>::contains_key(1, 0));
+		assert_noop!(
+			TemplateModule::dismiss_reports(RuntimeOrigin::root(), bounded_vec("Alice"), 0),
+			Error::<Test>::NoReports
+		);
+
+		// Removing a tweet clears its reports too.
+		assert_ok!(TemplateModule::report_tweet(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, bounded_vec("spam")));
+		assert_ok!(TemplateModule::remove_tweet(RuntimeOrigin::root(), bounded_vec("Alice"), 0));
+		System::assert_last_event(Event::TweetRemoved { name: bounded_vec("Alice"), tweet_id: 0 }.into());
+		assert!(crate::Tweets::<Test>::get(bounded_vec("Alice"), 0).is_none());
+		assert!(!Reports::<Test>::contains_key(1, 0));
+	})
+}
+
+#[test]
+fn hidden_tweets_and_suspensions_can_be_appealed() {
+	use crate::{AppealSubject, HiddenTweets, Suspended};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Hot take")));
+
+		assert_noop!(
+			TemplateModule::appeal(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), AppealSubject::Tweet(0), bounded_vec("?")),
+			Error::<Test>::NothingToAppeal
+		);
+
+		// A hidden tweet stays stored and comes back when the appeal succeeds.
+		assert_ok!(TemplateModule::hide_tweet(RuntimeOrigin::root(), bounded_vec("Alice"), 0));
+		System::assert_last_event(Event::TweetHidden { name: bounded_vec("Alice"), tweet_id: 0 }.into());
+		assert!(HiddenTweets::<Test>::contains_key(1, 0));
+		assert!(crate::Tweets::<Test>::contains_key(bounded_vec("Alice"), 0));
+
+		assert_ok!(TemplateModule::appeal(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), AppealSubject::Tweet(0), bounded_vec("It was satire")));
+		System::assert_last_event(Event::AppealFiled { name: bounded_vec("Alice"), subject: AppealSubject::Tweet(0) }.into());
+		assert_noop!(
+			TemplateModule::appeal(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), AppealSubject::Tweet(0), bounded_vec("Please")),
+			Error::<Test>::AppealPending
+		);
+		assert_ok!(TemplateModule::resolve_appeal(RuntimeOrigin::root(), bounded_vec("Alice"), AppealSubject::Tweet(0), true));
+		System::assert_last_event(Event::AppealResolved { name: bounded_vec("Alice"), subject: AppealSubject::Tweet(0), reinstated: true }.into());
+		assert!(!HiddenTweets::<Test>::contains_key(1, 0));
+
+		// Suspended users can't tweet, and a rejected appeal leaves the suspension in place.
+		assert_ok!(TemplateModule::suspend_name(RuntimeOrigin::root(), bounded_vec("Alice")));
+		System::assert_last_event(Event::NameSuspended { name: bounded_vec("Alice") }.into());
+		assert_noop!(
+			TemplateModule::suspend_name(RuntimeOrigin::root(), bounded_vec("Alice")),
+			Error::<Test>::AlreadySuspended
+		);
+		assert_noop!(
+			TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Hello?")),
+			Error::<Test>::Suspended
+		);
+
+		assert_ok!(TemplateModule::appeal(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), AppealSubject::Suspension, bounded_vec("Sorry")));
+		assert_ok!(TemplateModule::resolve_appeal(RuntimeOrigin::root(), bounded_vec("Alice"), AppealSubject::Suspension, false));
+		assert!(Suspended::<Test>::contains_key(1));
+		assert_noop!(
+			TemplateModule::resolve_appeal(RuntimeOrigin::root(), bounded_vec("Alice"), AppealSubject::Suspension, true),
+			Error::<Test>::AppealNotFound
+		);
+	})
+}
+
+#[test]
+fn admins_suspend_for_a_while_and_ban_for_good() {
+	use crate::AppealSubject;
+	use sp_runtime::DispatchError;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+
+		assert_noop!(
+			TemplateModule::suspend_user(RuntimeOrigin::signed(2), bounded_vec("Alice"), 5),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::suspend_user(RuntimeOrigin::root(), bounded_vec("Alice"), 1),
+			Error::<Test>::SuspensionInPast
+		);
+
+		// Suspended users can't post, rename or register another name from the same account.
+		assert_ok!(TemplateModule::suspend_user(RuntimeOrigin::root(), bounded_vec("Alice"), 5));
+		System::assert_last_event(Event::UserSuspended { name: bounded_vec("Alice"), until: 5 }.into());
+		assert_noop!(
+			TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Hi")),
+			Error::<Test>::Suspended
+		);
+		assert_noop!(
+			TemplateModule::update_user_name(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Alicia")),
+			Error::<Test>::Suspended
+		);
+		assert_noop!(
+			TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alicia"), bounded_vec("123"), 1990, 5, 4),
+			Error::<Test>::Suspended
+		);
+
+		// The suspension ends on its own.
+		System::set_block_number(5);
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("I'm back")));
+
+		// Bans don't expire and can't be appealed, only an admin can lift them.
+		assert_ok!(TemplateModule::ban_user(RuntimeOrigin::root(), bounded_vec("Alice")));
+		System::assert_last_event(Event::UserBanned { name: bounded_vec("Alice") }.into());
+		System::set_block_number(1_000);
+		assert_noop!(
+			TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Hi")),
+			Error::<Test>::Banned
+		);
+		assert_noop!(
+			TemplateModule::suspend_user(RuntimeOrigin::root(), bounded_vec("Alice"), 2_000),
+			Error::<Test>::Banned
+		);
+		assert_noop!(
+			TemplateModule::appeal(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), AppealSubject::Suspension, bounded_vec("Please")),
+			Error::<Test>::NothingToAppeal
+		);
+
+		assert_ok!(TemplateModule::unsuspend_user(RuntimeOrigin::root(), bounded_vec("Alice")));
+		System::assert_last_event(Event::UserUnsuspended { name: bounded_vec("Alice") }.into());
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Hi again")));
+		assert_noop!(
+			TemplateModule::unsuspend_user(RuntimeOrigin::root(), bounded_vec("Alice")),
+			Error::<Test>::NotSuspended
+		);
+	})
+}
+
+#[test]
+fn registrars_judge_names_shown_in_profiles() {
+	use crate::{Birthday, Judgement};
+	use sp_runtime::DispatchError;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Acme"), bounded_vec("123"), 1990, 5, 4));
+
+		// Only root appoints registrars, and only registrars judge.
+		assert_noop!(TemplateModule::add_registrar(RuntimeOrigin::signed(10), 10), DispatchError::BadOrigin);
+		assert_noop!(
+			TemplateModule::provide_judgement(RuntimeOrigin::signed(10), bounded_vec("Acme"), Judgement::KnownGood),
+			Error::<Test>::NotRegistrar
+		);
+		assert_ok!(TemplateModule::add_registrar(RuntimeOrigin::root(), 10));
+		System::assert_last_event(Event::RegistrarAdded { registrar: 10 }.into());
+		assert_noop!(TemplateModule::add_registrar(RuntimeOrigin::root(), 10), Error::<Test>::AlreadyRegistrar);
+		assert_ok!(TemplateModule::add_registrar(RuntimeOrigin::root(), 11));
+		assert_noop!(TemplateModule::add_registrar(RuntimeOrigin::root(), 12), Error::<Test>::TooManyRegistrars);
+
+		assert_noop!(
+			TemplateModule::provide_judgement(RuntimeOrigin::signed(10), bounded_vec("Nobody"), Judgement::KnownGood),
+			Error::<Test>::UserNotFound
+		);
+		assert_ok!(TemplateModule::provide_judgement(RuntimeOrigin::signed(10), bounded_vec("Acme"), Judgement::Reasonable));
+		assert_ok!(TemplateModule::provide_judgement(RuntimeOrigin::signed(10), bounded_vec("Acme"), Judgement::KnownGood));
+		System::assert_last_event(
+			Event::JudgementGiven { name: bounded_vec("Acme"), registrar: 10, judgement: Judgement::KnownGood }.into(),
+		);
+		assert_ok!(TemplateModule::provide_judgement(RuntimeOrigin::signed(11), bounded_vec("Acme"), Judgement::Reasonable));
+
+		let profile = TemplateModule::profile(b"Acme").unwrap();
+		assert_eq!(profile.account, 1);
+		assert_eq!(profile.birthday, Some(Birthday { year: 1990, month: 5, day: 4 }));
+		assert_eq!(profile.judgements, vec![(10, Judgement::KnownGood), (11, Judgement::Reasonable)]);
+		assert!(TemplateModule::profile(b"Nobody").is_none());
+
+		// Judgements of removed registrars and revoked judgements disappear from the profile.
+		assert_ok!(TemplateModule::remove_registrar(RuntimeOrigin::root(), 11));
+		assert_eq!(TemplateModule::profile(b"Acme").unwrap().judgements, vec![(10, Judgement::KnownGood)]);
+		assert_ok!(TemplateModule::revoke_judgement(RuntimeOrigin::signed(10), bounded_vec("Acme")));
+		System::assert_last_event(Event::JudgementRevoked { name: bounded_vec("Acme"), registrar: 10 }.into());
+		assert_noop!(
+			TemplateModule::revoke_judgement(RuntimeOrigin::signed(10), bounded_vec("Acme")),
+			Error::<Test>::NoJudgement
+		);
+
+		// Judgements vouch for a name, so renaming clears them.
+		assert_ok!(TemplateModule::provide_judgement(RuntimeOrigin::signed(10), bounded_vec("Acme"), Judgement::KnownGood));
+		assert_ok!(TemplateModule::update_user_name(RuntimeOrigin::signed(1), bounded_vec("Acme"), bounded_vec("123"), bounded_vec("Acme Inc")));
+		assert!(TemplateModule::profile(b"Acme Inc").unwrap().judgements.is_empty());
+	})
+}
+
+#[test]
+fn identity_judgements_are_shown_and_can_be_required() {
+	use crate::Judgement;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		IdentityJudgements::set(vec![(1, Judgement::KnownGood), (3, Judgement::Erroneous)]);
+
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 1991, 6, 5));
+		assert_eq!(TemplateModule::profile(b"Bob").unwrap().identity, None);
+
+		RequireIdentity::set(true);
+		assert_noop!(
+			TemplateModule::create_user(RuntimeOrigin::signed(4), bounded_vec("Dave"), bounded_vec("123"), 1990, 5, 4),
+			Error::<Test>::IdentityRequired
+		);
+		assert_noop!(
+			TemplateModule::create_user(RuntimeOrigin::signed(3), bounded_vec("Carol"), bounded_vec("123"), 1990, 5, 4),
+			Error::<Test>::IdentityRequired
+		);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_eq!(TemplateModule::profile(b"Alice").unwrap().identity, Some(Judgement::KnownGood));
+	})
+}
+
+#[test]
+fn direct_messages_hold_a_deposit_until_deleted() {
+	use frame_support::traits::fungible::InspectHold;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 1991, 6, 5));
+		let ciphertext = || BoundedVec::try_from(vec![7; 64]).unwrap();
+		let send = |from: u64, to: &str| {
+			TemplateModule::send_message(RuntimeOrigin::signed(from), bounded_vec(to), ciphertext(), [1; 24], [2; 32])
+		};
+
+		assert_noop!(send(1, "Bob"), Error::<Test>::NoMessagingKey);
+		assert_ok!(TemplateModule::register_messaging_key(RuntimeOrigin::signed(2), [9; 32]));
+		System::assert_last_event(Event::MessagingKeyRegistered { name: bounded_vec("Bob") }.into());
+		assert_eq!(TemplateModule::profile(b"Bob").unwrap().messaging_key, Some([9; 32]));
+		assert_noop!(send(2, "Bob"), Error::<Test>::CannotTargetSelf);
+		assert_noop!(send(1, "Nobody"), Error::<Test>::UserNotFound);
+
+		assert_ok!(send(1, "Bob"));
+		System::assert_last_event(
+			Event::MessageSent { from: bounded_vec("Alice"), to: bounded_vec("Bob"), message_id: 0 }.into(),
+		);
+		assert_ok!(send(1, "Bob"));
+		assert_ok!(send(1, "Bob"));
+		assert_eq!(Balances::balance_on_hold(&crate::HoldReason::MessageDeposit.into(), &1), 30);
+		assert_noop!(send(1, "Bob"), Error::<Test>::ConversationFull);
+
+		// The inbox pages through messages oldest first, from both participants' side.
+		let page = TemplateModule::messages(b"Bob", b"Alice", 0, 2);
+		assert_eq!(page.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![0, 1]);
+		assert_eq!(page[0].1.from, 1);
+		assert_eq!(page[0].1.ciphertext, ciphertext());
+		assert_eq!(TemplateModule::messages(b"Alice", b"Bob", 2, 2).len(), 1);
+
+		// Either participant can delete, and the deposit goes back to the sender.
+		assert_ok!(TemplateModule::delete_message(RuntimeOrigin::signed(2), 1, 1));
+		System::assert_last_event(Event::MessageDeleted { by: 2, with: 1, message_id: 1 }.into());
+		assert_noop!(
+			TemplateModule::delete_message(RuntimeOrigin::signed(1), 2, 1),
+			Error::<Test>::MessageNotFound
+		);
+		assert_eq!(Balances::balance_on_hold(&crate::HoldReason::MessageDeposit.into(), &1), 20);
+		assert_eq!(
+			TemplateModule::messages(b"Alice", b"Bob", 0, 10).iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+			vec![0, 2]
+		);
+		assert_ok!(send(1, "Bob"));
+		assert_eq!(TemplateModule::messages(b"Alice", b"Bob", 3, 10)[0].0, 3);
+
+		// Blocked senders cannot message the blocker.
+		assert_ok!(TemplateModule::block_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), bounded_vec("Alice")));
+		assert_ok!(TemplateModule::delete_message(RuntimeOrigin::signed(1), 2, 0));
+		assert_noop!(send(1, "Bob"), Error::<Test>::Blocked);
+	})
+}
+
+#[test]
+fn deleted_users_get_their_message_deposits_back() {
+	use crate::HoldReason;
+	use frame_support::traits::fungible::InspectHold;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 1991, 6, 5));
+		assert_ok!(TemplateModule::register_messaging_key(RuntimeOrigin::signed(1), [8; 32]));
+		assert_ok!(TemplateModule::register_messaging_key(RuntimeOrigin::signed(2), [9; 32]));
+		let send = |from: u64, to: &str| {
+			TemplateModule::send_message(RuntimeOrigin::signed(from), bounded_vec(to), BoundedVec::truncate_from(vec![7; 64]), [1; 24], [2; 32])
+		};
+		let held = |who: u64| Balances::balance_on_hold(&HoldReason::MessageDeposit.into(), &who);
+		assert_ok!(send(1, "Bob"));
+		assert_ok!(send(1, "Bob"));
+		assert_ok!(send(2, "Alice"));
+		assert_eq!((held(1), held(2)), (20, 10));
+
+		// Deleting a name gives back the deposits of the messages it sent.
+		assert_ok!(TemplateModule::delete_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123")));
+		assert_eq!(held(1), 0);
+
+		// The messages stay, and either side can still delete them by account.
+		assert_ok!(TemplateModule::delete_message(RuntimeOrigin::signed(2), 1, 0));
+		System::assert_last_event(Event::MessageDeleted { by: 2, with: 1, message_id: 0 }.into());
+
+		// Deleting a message sent before the name was deleted leaves newer deposits alone.
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alicia"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(send(1, "Bob"));
+		assert_eq!(held(1), 10);
+		assert_ok!(TemplateModule::delete_message(RuntimeOrigin::signed(1), 2, 1));
+		assert_eq!(held(1), 10);
+
+		// Once the peer's name is gone too, its conversation can still be cleaned up.
+		assert_ok!(TemplateModule::delete_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456")));
+		assert_eq!(held(2), 0);
+		assert_ok!(TemplateModule::delete_message(RuntimeOrigin::signed(1), 2, 3));
+		assert_ok!(TemplateModule::delete_message(RuntimeOrigin::signed(1), 2, 2));
+		assert_eq!(held(1), 0);
+		assert_eq!(crate::Messages::<Test>::iter().count(), 0);
+	})
+}
+
+#[test]
+fn polls_take_one_vote_per_user_and_close_at_their_end_block() {
+	use crate::PollsEndingAt;
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for (who, name) in [(1, "Alice"), (2, "Bob"), (3, "Carol")] {
+			assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(who), bounded_vec(name), bounded_vec("123"), 1990, 5, 4));
+		}
+		let options = |options: &[&str]| {
+			BoundedVec::truncate_from(options.iter().map(|option| BoundedVec::truncate_from(option.as_bytes().to_vec())).collect())
+		};
+		let create_poll = |content: &str, options, ends_at| {
+			TemplateModule::create_poll_tweet(
+				RuntimeOrigin::signed(1),
+				bounded_vec("Alice"),
+				bounded_vec("123"),
+				bounded_tweet(content),
+				options,
+				ends_at,
+			)
+		};
+
+		assert_noop!(create_poll("Tea?", options(&["yes"]), 10), Error::<Test>::InvalidPollOptions);
+		assert_noop!(create_poll("Tea?", options(&["yes", ""]), 10), Error::<Test>::InvalidPollOptions);
+		assert_noop!(create_poll("Tea?", options(&["yes", "no"]), 1), Error::<Test>::InvalidPollEnd);
+		assert_noop!(create_poll("Tea?", options(&["yes", "no"]), 102), Error::<Test>::InvalidPollEnd);
+
+		assert_ok!(create_poll("Tea?", options(&["yes", "no", "coffee"]), 10));
+		System::assert_last_event(Event::PollCreated { name: bounded_vec("Alice"), tweet_id: 0, ends_at: 10 }.into());
+		assert_ok!(create_poll("Cake?", options(&["yes", "no"]), 10));
+		assert_noop!(create_poll("Pie?", options(&["yes", "no"]), 10), Error::<Test>::TooManyPollsEnding);
+
+		let vote = |who: u64, tweet_id, option| TemplateModule::vote_poll(RuntimeOrigin::signed(who), bounded_vec("Alice"), tweet_id, option);
+		assert_ok!(vote(2, 0, 2));
+		System::assert_last_event(
+			Event::PollVoted { name: bounded_vec("Bob"), author: bounded_vec("Alice"), tweet_id: 0, option: 2 }.into(),
+		);
+		assert_noop!(vote(2, 0, 0), Error::<Test>::AlreadyVoted);
+		assert_noop!(vote(3, 0, 3), Error::<Test>::InvalidPollOption);
+		assert_noop!(vote(4, 0, 0), Error::<Test>::UserNotFound);
+		assert_ok!(vote(3, 0, 2));
+		assert_ok!(vote(1, 0, 0));
+		assert_eq!(TemplateModule::poll(b"Alice", 0).unwrap().tallies.to_vec(), vec![1, 0, 2]);
+
+		// Deleting a tweet removes its poll, which then never closes.
+		assert_ok!(TemplateModule::delete_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1));
+		assert!(TemplateModule::poll(b"Alice", 1).is_none());
+		assert_eq!(PollsEndingAt::<Test>::get(10).len(), 1);
+
+		System::set_block_number(10);
+		TemplateModule::on_initialize(10);
+		System::assert_last_event(
+			Event::PollClosed { author: bounded_vec("Alice"), tweet_id: 0, tallies: BoundedVec::truncate_from(vec![1, 0, 2]) }.into(),
+		);
+		assert!(TemplateModule::poll(b"Alice", 0).unwrap().closed);
+		assert!(PollsEndingAt::<Test>::get(10).is_empty());
+		assert_noop!(vote(2, 0, 1), Error::<Test>::PollClosed);
+		assert_noop!(vote(2, 1, 1), Error::<Test>::PollNotFound);
+	})
+}
+
+#[test]
+fn a_name_keeps_its_poll_votes_when_it_changes_hands() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for (who, name) in [(1, "Alice"), (2, "Bob")] {
+			assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(who), bounded_vec(name), bounded_vec("123"), 1990, 5, 4));
+		}
+		let options = BoundedVec::truncate_from(["yes", "no"].map(|option| BoundedVec::truncate_from(option.as_bytes().to_vec())).to_vec());
+		assert_ok!(TemplateModule::create_poll_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Tea?"), options, 10));
+		let vote = |who: u64| TemplateModule::vote_poll(RuntimeOrigin::signed(who), bounded_vec("Alice"), 0, 0);
+		assert_ok!(vote(2));
+
+		// Bob's vote goes along with his name, and his old account can vote under a new name.
+		assert_ok!(TemplateModule::transfer_name(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("123"), 3));
+		assert_noop!(vote(3), Error::<Test>::AlreadyVoted);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Robert"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(vote(2));
+		assert_eq!(TemplateModule::poll(b"Alice", 0).unwrap().tallies.to_vec(), vec![2, 0]);
+	})
+}
+
+#[test]
+fn deleting_and_registering_a_name_again_does_not_reset_its_votes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for (who, name) in [(1, "Alice"), (2, "Bob")] {
+			assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(who), bounded_vec(name), bounded_vec("123"), 1990, 5, 4));
+		}
+		let options = BoundedVec::truncate_from(["yes", "no"].map(|option| BoundedVec::truncate_from(option.as_bytes().to_vec())).to_vec());
+		assert_ok!(TemplateModule::create_poll_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Tea?"), options, 10));
+		assert_ok!(TemplateModule::vote_poll(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, 0));
+
+		assert_ok!(TemplateModule::delete_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("123")));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("123"), 1990, 5, 4));
+		assert_noop!(
+			TemplateModule::vote_poll(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, 1),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_eq!(TemplateModule::poll(b"Alice", 0).unwrap().tallies.to_vec(), vec![1, 0]);
+	})
+}
+
+#[test]
+fn scheduled_tweets_are_posted_when_due_unless_cancelled() {
+	use crate::{ScheduledTweets, Tweets};
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		let schedule = |content: &str, at| {
+			TemplateModule::schedule_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet(content), at)
+		};
+
+		assert_noop!(schedule("Too late", 1), Error::<Test>::ScheduleInPast);
+		assert_noop!(
+			TemplateModule::schedule_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("wrong"), bounded_tweet("Hi"), 5),
+			Error::<Test>::InvalidCredentials
+		);
+		assert_ok!(schedule("Launch day!", 5));
+		System::assert_last_event(Event::TweetScheduled { name: bounded_vec("Alice"), schedule_id: 0, at: 5 }.into());
+		assert_noop!(schedule("Launch week!", 5), Error::<Test>::TooManyScheduledForBlock);
+		assert_ok!(schedule("Launch week!", 6));
+		assert_noop!(schedule("Launch month!", 7), Error::<Test>::TooManyScheduledTweets);
+
+		assert_ok!(TemplateModule::cancel_scheduled_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1));
+		System::assert_last_event(Event::ScheduledTweetCancelled { name: bounded_vec("Alice"), schedule_id: 1 }.into());
+		assert_noop!(
+			TemplateModule::cancel_scheduled_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1),
+			Error::<Test>::ScheduledTweetNotFound
+		);
+
+		System::set_block_number(5);
+		Scheduler::on_initialize(5);
+		assert_eq!(Tweets::<Test>::get(bounded_vec("Alice"), 0).unwrap().content, bounded_tweet("Launch day!"));
+		assert!(Tweets::<Test>::get(bounded_vec("Alice"), 1).is_none());
+		assert!(ScheduledTweets::<Test>::get(1).is_empty());
+		assert_noop!(
+			TemplateModule::publish_scheduled_tweet(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::ScheduledTweetNotFound
+		);
+
+		// Publishing early takes the task off the agenda, so it does not run again later.
+		assert_ok!(schedule("Early bird", 7));
+		assert_ok!(TemplateModule::publish_scheduled_tweet(RuntimeOrigin::signed(1), 2));
+		assert_eq!(Tweets::<Test>::get(bounded_vec("Alice"), 1).unwrap().content, bounded_tweet("Early bird"));
+		assert_eq!(pallet_scheduler::Agenda::<Test>::get(7).iter().flatten().count(), 0);
+
+		// Deleting the user cancels whatever they still have scheduled.
+		assert_ok!(schedule("Sequel", 8));
+		assert_eq!(pallet_scheduler::Agenda::<Test>::get(8).iter().flatten().count(), 1);
+		assert_ok!(TemplateModule::delete_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123")));
+		assert!(ScheduledTweets::<Test>::get(1).is_empty());
+		assert_eq!(pallet_scheduler::Agenda::<Test>::get(8).iter().flatten().count(), 0);
+	})
+}
+
+#[test]
+fn expiring_tweets_are_removed_in_on_idle() {
+	use crate::{ExpiringAt, HoldReason, Tweets};
+	use frame_support::{traits::{fungible::InspectHold, Hooks}, weights::Weight};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		let post = |content: &str, expires_at| {
+			TemplateModule::create_expiring_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet(content), expires_at)
+		};
+
+		assert_noop!(post("Gone already", 1), Error::<Test>::ExpiryInPast);
+		assert_ok!(post("Flash sale!", 3));
+		assert_ok!(post("Still on", 3));
+		assert_noop!(post("One more", 3), Error::<Test>::TooManyTweetsExpiring);
+		assert_ok!(post("Tomorrow only", 5));
+		assert_eq!(Tweets::<Test>::get(bounded_vec("Alice"), 0).unwrap().expires_at, Some(3));
+		// Each expiring tweet holds a deposit until it is gone.
+		let held = || Balances::balance_on_hold(&HoldReason::ExpiringTweetDeposit.into(), &1);
+		assert_eq!(held(), 15);
+
+		// Nothing expires before its block, however much weight is left.
+		TemplateModule::on_idle(2, Weight::MAX);
+		assert!(Tweets::<Test>::contains_key(bounded_vec("Alice"), 0));
+
+		// A deleted tweet's entry is skipped.
+		assert_ok!(TemplateModule::delete_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1));
+		assert_eq!(held(), 10);
+
+		// Blocks without idle time are caught up on later.
+		System::set_block_number(6);
+		TemplateModule::on_idle(6, Weight::MAX);
+		System::assert_has_event(Event::TweetExpired { name: bounded_vec("Alice"), tweet_id: 0 }.into());
+		System::assert_last_event(Event::TweetExpired { name: bounded_vec("Alice"), tweet_id: 2 }.into());
+		assert!(!Tweets::<Test>::contains_key(bounded_vec("Alice"), 0));
+		assert!(!Tweets::<Test>::contains_key(bounded_vec("Alice"), 2));
+		assert!(ExpiringAt::<Test>::get(3).is_empty());
+		assert!(ExpiringAt::<Test>::get(5).is_empty());
+		assert_eq!(System::events().iter().filter(|record| matches!(record.event, RuntimeEvent::TemplateModule(Event::TweetExpired { .. }))).count(), 2);
+		assert_eq!(held(), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+	})
+}
+
+#[test]
+fn migration_to_v1_keeps_tweets_without_expiry() {
+	use crate::{migrations, Tweets};
+	use codec::Encode;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<TemplateModule>();
+		let old = migrations::v1::OldTweet { name: bounded_vec("Alice"), content: bounded_tweet("Hello"), timestamp: 7 };
+		frame_support::storage::unhashed::put_raw(&Tweets::<Test>::hashed_key_for(bounded_vec("Alice"), 0), &old.encode());
+
+		migrations::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		let tweet = Tweets::<Test>::get(bounded_vec("Alice"), 0).unwrap();
+		assert_eq!((tweet.content, tweet.timestamp, tweet.expires_at), (bounded_tweet("Hello"), 7, None));
+		assert_eq!(TemplateModule::on_chain_storage_version(), 1);
+	})
+}
+
+#[test]
+fn pins_and_bookmarks_follow_tweet_deletion() {
+	use crate::{Bookmarks, BookmarkedBy, PinnedTweets, StaleBookmarks};
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 1990, 5, 4));
+		for content in ["First", "Second", "Third"] {
+			assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet(content)));
+		}
+		let pin = |tweet_id| TemplateModule::pin_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), tweet_id);
+		let bookmark = |who, tweet_id| TemplateModule::bookmark(RuntimeOrigin::signed(who), bounded_vec("Alice"), tweet_id);
+
+		assert_noop!(pin(7), Error::<Test>::TweetNotFound);
+		assert_ok!(pin(2));
+		assert_noop!(pin(2), Error::<Test>::AlreadyPinned);
+		assert_ok!(pin(0));
+		assert_noop!(pin(1), Error::<Test>::TooManyPins);
+		System::assert_last_event(Event::TweetPinned { name: bounded_vec("Alice"), tweet_id: 0 }.into());
+		assert_eq!(TemplateModule::pinned_tweets(b"Alice").into_iter().map(|(id, _)| id).collect::<Vec<_>>(), vec![2, 0]);
+		assert_ok!(TemplateModule::unpin_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 2));
+		assert_noop!(TemplateModule::unpin_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 2), Error::<Test>::NotPinned);
+
+		// Only registered users can bookmark, and at most `MaxBookmarks` tweets.
+		assert_noop!(bookmark(3, 0), Error::<Test>::UserNotFound);
+		assert_noop!(bookmark(2, 7), Error::<Test>::TweetNotFound);
+		assert_ok!(bookmark(2, 0));
+		assert_noop!(bookmark(2, 0), Error::<Test>::AlreadyBookmarked);
+		assert_ok!(bookmark(2, 1));
+		assert_noop!(bookmark(2, 2), Error::<Test>::TooManyBookmarks);
+		assert_ok!(TemplateModule::unbookmark(RuntimeOrigin::signed(2), bounded_vec("Alice"), 1));
+		assert_noop!(TemplateModule::unbookmark(RuntimeOrigin::signed(2), bounded_vec("Alice"), 1), Error::<Test>::NotBookmarked);
+		assert_ok!(bookmark(2, 2));
+		assert_ok!(bookmark(1, 0));
+		let contents = |tweets: Vec<(u32, crate::Tweet<u64>)>| tweets.into_iter().map(|(_, tweet)| tweet.content).collect::<Vec<_>>();
+		assert_eq!(contents(TemplateModule::bookmarks(&2)), vec![bounded_tweet("First"), bounded_tweet("Third")]);
+
+		// Deleting a tweet unpins it at once and leaves its bookmarks to `on_idle`.
+		assert_ok!(TemplateModule::delete_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 0));
+		assert!(PinnedTweets::<Test>::get(1).is_empty());
+		assert!(StaleBookmarks::<Test>::contains_key((1, 0)));
+		assert_eq!(contents(TemplateModule::bookmarks(&2)), vec![bounded_tweet("Third")]);
+
+		TemplateModule::on_idle(1, Weight::MAX);
+		assert!(!StaleBookmarks::<Test>::contains_key((1, 0)));
+		assert_eq!(BookmarkedBy::<Test>::iter_prefix((1, 0)).count(), 0);
+		assert_eq!(Bookmarks::<Test>::get(2).into_inner(), vec![(1, 2)]);
+		assert!(Bookmarks::<Test>::get(1).is_empty());
+	})
+}
+
+#[test]
+fn lists_merge_their_members_recent_tweets() {
+	use crate::{ListCount, Lists};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for (who, name) in [(1, "Alice"), (2, "Bob"), (3, "Carol")] {
+			assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(who), bounded_vec(name), bounded_vec("123"), 1990, 5, 4));
+		}
+		let list = |s: &str| BoundedVec::<u8, ConstU32<64>>::try_from(s.as_bytes().to_vec()).unwrap();
+		let tweet = |who, name: &str, content: &str, at| {
+			pallet_timestamp::Now::<Test>::put(at);
+			assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(who), bounded_vec(name), bounded_vec("123"), bounded_tweet(content)));
+		};
+		let add = |member: &str| TemplateModule::add_to_list(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), list("Friends"), bounded_vec(member));
+
+		assert_noop!(add("Bob"), Error::<Test>::ListNotFound);
+		assert_ok!(TemplateModule::create_list(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), list("Friends"), true));
+		assert_noop!(TemplateModule::create_list(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), list("Friends"), false), Error::<Test>::ListAlreadyExists);
+		assert_ok!(TemplateModule::create_list(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), list("Work"), false));
+		assert_noop!(TemplateModule::create_list(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), list("More"), false), Error::<Test>::TooManyLists);
+
+		assert_noop!(add("Dave"), Error::<Test>::UserNotFound);
+		assert_ok!(add("Bob"));
+		assert_noop!(add("Bob"), Error::<Test>::AlreadyInList);
+		assert_ok!(add("Carol"));
+		assert_noop!(add("Alice"), Error::<Test>::ListFull);
+		System::assert_last_event(Event::AddedToList { name: bounded_vec("Alice"), list: list("Friends"), member: bounded_vec("Carol") }.into());
+
+		tweet(2, "Bob", "Bob old", 10);
+		tweet(3, "Carol", "Carol mid", 20);
+		tweet(2, "Bob", "Bob new", 30);
+		tweet(1, "Alice", "Not a member", 40);
+		let contents = |viewer, limit| {
+			TemplateModule::list_tweets(b"Alice", b"Friends", viewer, limit).into_iter().map(|(_, tweet)| tweet.content).collect::<Vec<_>>()
+		};
+		assert_eq!(contents(Some(1), 10), vec![bounded_tweet("Bob new"), bounded_tweet("Carol mid"), bounded_tweet("Bob old")]);
+		assert_eq!(contents(Some(1), 2), vec![bounded_tweet("Bob new"), bounded_tweet("Carol mid")]);
+		// Private lists are only shown to their owner.
+		assert!(contents(Some(2), 10).is_empty());
+		assert!(contents(None, 10).is_empty());
+
+		assert_ok!(TemplateModule::remove_from_list(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), list("Friends"), bounded_vec("Bob")));
+		assert_noop!(TemplateModule::remove_from_list(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), list("Friends"), bounded_vec("Bob")), Error::<Test>::NotInList);
+		assert_eq!(contents(Some(1), 10), vec![bounded_tweet("Carol mid")]);
+
+		// Lists follow their owner's name.
+		assert_ok!(TemplateModule::update_user_name(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Alicia")));
+		assert!(TemplateModule::list_tweets(b"Alice", b"Friends", Some(1), 10).is_empty());
+		assert_eq!(TemplateModule::list_tweets(b"Alicia", b"Friends", Some(1), 10).len(), 1);
+		assert_eq!(ListCount::<Test>::get(bounded_vec("Alicia")), 2);
+
+		assert_ok!(TemplateModule::delete_list(RuntimeOrigin::signed(1), bounded_vec("Alicia"), bounded_vec("123"), list("Work")));
+		assert_noop!(TemplateModule::delete_list(RuntimeOrigin::signed(1), bounded_vec("Alicia"), bounded_vec("123"), list("Work")), Error::<Test>::ListNotFound);
+		assert_eq!(ListCount::<Test>::get(bounded_vec("Alicia")), 1);
+
+		assert_ok!(TemplateModule::delete_user(RuntimeOrigin::signed(1), bounded_vec("Alicia"), bounded_vec("123")));
+		assert!(!Lists::<Test>::contains_key(bounded_vec("Alicia"), list("Friends")));
+		assert_eq!(ListCount::<Test>::get(bounded_vec("Alicia")), 0);
+	})
+}
+
+#[test]
+fn tips_are_transferred_and_added_up_per_tweet() {
+	use crate::Tips;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Tip jar")));
+		let tip = |who, tweet_id, amount| TemplateModule::tip_tweet(RuntimeOrigin::signed(who), bounded_vec("Alice"), tweet_id, amount);
+
+		assert_noop!(tip(2, 0, 0), Error::<Test>::ZeroTip);
+		assert_noop!(tip(2, 7, 5), Error::<Test>::TweetNotFound);
+		assert_noop!(tip(1, 0, 5), Error::<Test>::CannotTargetSelf);
+		// Tips never reap the tipper's account.
+		assert!(tip(2, 0, 100).is_err());
+
+		assert_ok!(tip(2, 0, 5));
+		assert_ok!(tip(3, 0, 7));
+		System::assert_last_event(Event::Tipped { who: 3, name: bounded_vec("Alice"), tweet_id: 0, amount: 7 }.into());
+		assert_eq!(Balances::free_balance(1), 112);
+		assert_eq!(Balances::free_balance(2), 95);
+		assert_eq!(TemplateModule::tips(b"Alice", 0), 12);
+
+		assert_ok!(TemplateModule::block_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Bob")));
+		assert_noop!(tip(2, 0, 5), Error::<Test>::Blocked);
+
+		assert_ok!(TemplateModule::delete_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 0));
+		assert!(!Tips::<Test>::contains_key(1, 0));
+	})
+}
+
+#[test]
+fn asset_tips_are_transferred_and_added_up_per_asset() {
+	use crate::AssetTips;
+	use frame_support::traits::fungibles::{Inspect, Mutate};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Tip jar")));
+		for asset in [10, 20, 30] {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset, 2, true, 1));
+			assert_ok!(<Assets as Mutate<u64>>::mint_into(asset, &2, 50));
+		}
+		let tip = |asset, amount| TemplateModule::tip_tweet_with_asset(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, asset, amount);
+
+		assert_noop!(tip(10, 0), Error::<Test>::ZeroTip);
+		assert_noop!(TemplateModule::tip_tweet_with_asset(RuntimeOrigin::signed(1), bounded_vec("Alice"), 0, 10, 5), Error::<Test>::CannotTargetSelf);
+		assert!(tip(10, 50).is_err());
+		assert_ok!(tip(10, 5));
+		assert_ok!(tip(10, 7));
+		assert_ok!(tip(20, 1));
+		System::assert_last_event(Event::TippedWithAsset { who: 2, name: bounded_vec("Alice"), tweet_id: 0, asset_id: 20, amount: 1 }.into());
+		// Each tweet keeps totals for at most `MaxTipAssets` assets.
+		assert_noop!(tip(30, 1), Error::<Test>::TooManyTipAssets);
+
+		assert_eq!(TemplateModule::asset_tips(b"Alice", 0), vec![(10, 12), (20, 1)]);
+		assert_eq!(<Assets as Inspect<u64>>::balance(10, &1), 12);
+		assert_eq!(<Assets as Inspect<u64>>::balance(10, &2), 38);
+		// Native tips are kept apart.
+		assert_eq!(TemplateModule::tips(b"Alice", 0), 0);
+
+		assert_ok!(TemplateModule::delete_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 0));
+		assert!(!AssetTips::<Test>::contains_key(1, 0));
+	})
+}
+
+#[test]
+fn avatars_are_only_shown_while_the_nft_is_owned() {
+	use crate::{mock::give_nft, Avatars};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		give_nft(7, 1, 1);
+		give_nft(7, 2, 2);
+
+		assert_noop!(TemplateModule::set_avatar(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 7, 2), Error::<Test>::NotNftOwner);
+		assert_noop!(TemplateModule::set_avatar(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 7, 3), Error::<Test>::NotNftOwner);
+		assert_noop!(TemplateModule::set_avatar(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("wrong"), 7, 1), Error::<Test>::InvalidCredentials);
+		assert_ok!(TemplateModule::set_avatar(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 7, 1));
+		System::assert_last_event(Event::AvatarSet { name: bounded_vec("Alice"), collection: 7, item: 1 }.into());
+		assert_eq!(TemplateModule::profile(b"Alice").unwrap().avatar, Some((7, 1)));
+		// Nobody else can clear an avatar whose NFT is still owned.
+		assert_noop!(TemplateModule::clear_avatar(RuntimeOrigin::signed(2), bounded_vec("Alice")), Error::<Test>::AvatarStillOwned);
+
+		// Once the NFT is transferred the profile stops showing it, and anyone can clear it before
+		// `on_idle` gets to it, not only the NFT's new owner.
+		give_nft(7, 1, 2);
+		assert_eq!(TemplateModule::profile(b"Alice").unwrap().avatar, None);
+		assert!(Avatars::<Test>::contains_key(1));
+		assert_ok!(TemplateModule::clear_avatar(RuntimeOrigin::signed(3), bounded_vec("Alice")));
+		System::assert_last_event(Event::AvatarCleared { name: bounded_vec("Alice") }.into());
+		assert!(!Avatars::<Test>::contains_key(1));
+		assert_noop!(TemplateModule::clear_avatar(RuntimeOrigin::signed(1), bounded_vec("Alice")), Error::<Test>::NoAvatar);
+
+		// Users can always clear their own avatar, and it goes with their name.
+		give_nft(7, 3, 1);
+		assert_ok!(TemplateModule::set_avatar(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 7, 3));
+		assert_ok!(TemplateModule::clear_avatar(RuntimeOrigin::signed(1), bounded_vec("Alice")));
+		assert_ok!(TemplateModule::set_avatar(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 7, 3));
+		assert_ok!(TemplateModule::delete_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123")));
+		assert!(!Avatars::<Test>::contains_key(1));
+	})
+}
+
+#[test]
+fn on_idle_removes_avatars_whose_nft_changed_hands() {
+	use crate::{mock::give_nft, AvatarCursor, Avatars};
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for (who, name) in [(1, "Alice"), (2, "Bob"), (3, "Carol")] {
+			assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(who), bounded_vec(name), bounded_vec("123"), 1990, 5, 4));
+			give_nft(7, who as u32, who);
+			assert_ok!(TemplateModule::set_avatar(RuntimeOrigin::signed(who), bounded_vec(name), bounded_vec("123"), 7, who as u32));
+		}
+		for item in 1..=3 {
+			give_nft(7, item, 4);
+		}
+		let stale = || (1..=3).filter(|who| Avatars::<Test>::contains_key(who)).count();
+
+		// Two avatars are checked per block, and the next block picks up after the last one.
+		TemplateModule::on_idle(1, Weight::MAX);
+		assert_eq!(stale(), 1);
+		assert!(AvatarCursor::<Test>::get().is_some());
+		TemplateModule::on_idle(2, Weight::MAX);
+		assert_eq!(stale(), 0);
+		assert!(System::events().iter().any(|record| {
+			record.event == Event::AvatarCleared { name: bounded_vec("Carol") }.into()
+		}));
+
+		// Running out of weight checks nothing.
+		give_nft(7, 5, 1);
+		assert_ok!(TemplateModule::set_avatar(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 7, 5));
+		give_nft(7, 5, 4);
+		TemplateModule::on_idle(3, Weight::zero());
+		assert_eq!(stale(), 1);
+	})
+}
+
+#[test]
+fn devices_act_for_a_name_within_their_scopes_until_they_expire() {
+	use crate::{DeviceCount, DeviceScope, Devices, PinnedTweets, Tweets};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		let scopes = |scopes: &[DeviceScope]| BoundedVec::truncate_from(scopes.to_vec());
+		let add_device = |device, scopes, expires_at| TemplateModule::add_device(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), device, scopes, expires_at);
+		let tweet = |who, password: &str| TemplateModule::create_tweet(RuntimeOrigin::signed(who), bounded_vec("Alice"), bounded_vec(password), bounded_tweet("From my phone"));
+
+		assert_noop!(add_device(1, scopes(&[DeviceScope::Post]), None), Error::<Test>::CannotTargetSelf);
+		assert_noop!(add_device(2, scopes(&[DeviceScope::Post]), Some(1)), Error::<Test>::ExpiryInPast);
+		assert_ok!(add_device(2, scopes(&[DeviceScope::Post]), Some(10)));
+		System::assert_last_event(Event::DeviceAdded { name: bounded_vec("Alice"), device: 2, scopes: scopes(&[DeviceScope::Post]), expires_at: Some(10) }.into());
+		// Devices can't manage devices themselves.
+		assert_noop!(TemplateModule::add_device(RuntimeOrigin::signed(2), bounded_vec("Alice"), bounded_vec("123"), 3, scopes(&[DeviceScope::Post]), None), Error::<Test>::NotAuthorized);
+
+		// Tweets posted by a device belong to the name, and devices still need its password.
+		assert_noop!(tweet(3, "123"), Error::<Test>::NotAuthorized);
+		assert_noop!(tweet(2, "wrong"), Error::<Test>::InvalidCredentials);
+		assert_ok!(tweet(2, "123"));
+		assert_eq!(Tweets::<Test>::get(bounded_vec("Alice"), 0).unwrap().name, bounded_vec("Alice"));
+		assert_noop!(TemplateModule::delete_tweet(RuntimeOrigin::signed(2), bounded_vec("Alice"), bounded_vec("123"), 0), Error::<Test>::MissingDeviceScope);
+
+		// Adding a device again updates its scopes.
+		assert_ok!(add_device(2, scopes(&[DeviceScope::Post, DeviceScope::Delete]), Some(10)));
+		assert_eq!(DeviceCount::<Test>::get(1), 1);
+		assert_ok!(TemplateModule::delete_tweet(RuntimeOrigin::signed(2), bounded_vec("Alice"), bounded_vec("123"), 0));
+
+		assert_ok!(add_device(3, scopes(&[DeviceScope::Profile]), None));
+		assert_noop!(add_device(4, scopes(&[DeviceScope::Profile]), None), Error::<Test>::TooManyDevices);
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Pin me")));
+		assert_ok!(TemplateModule::pin_tweet(RuntimeOrigin::signed(3), bounded_vec("Alice"), bounded_vec("123"), 1));
+		assert_eq!(PinnedTweets::<Test>::get(1).into_inner(), vec![1]);
+
+		System::set_block_number(10);
+		assert_noop!(tweet(2, "123"), Error::<Test>::DeviceExpired);
+
+		assert_ok!(TemplateModule::remove_device(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 2));
+		System::assert_last_event(Event::DeviceRemoved { name: bounded_vec("Alice"), device: 2 }.into());
+		assert_noop!(TemplateModule::remove_device(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 2), Error::<Test>::DeviceNotFound);
+		assert_ok!(TemplateModule::delete_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123")));
+		assert!(!Devices::<Test>::contains_key(1, 3));
+		assert_eq!(DeviceCount::<Test>::get(1), 0);
+	})
+}
+
+#[test]
+fn friends_recover_a_name_to_a_new_account_after_the_delay() {
+	use crate::{HoldReason, Names, PinnedTweets, PollsEndingAt, Tips};
+	use frame_support::traits::fungible::InspectHold;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 1991, 6, 5));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(3), bounded_vec("Charlie"), bounded_vec("789"), 1992, 7, 6));
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Hello")));
+		assert_ok!(TemplateModule::pin_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 0));
+		assert_ok!(TemplateModule::tip_tweet(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, 5));
+		let options = BoundedVec::truncate_from(vec![BoundedVec::truncate_from(b"yes".to_vec()), BoundedVec::truncate_from(b"no".to_vec())]);
+		assert_ok!(TemplateModule::create_poll_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Tea?"), options, 20));
+		let friends = |names: &[&str]| BoundedVec::truncate_from(names.iter().map(|name| bounded_vec(name)).collect());
+		let set_recovery = |names: &[&str], threshold| TemplateModule::set_recovery(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), friends(names), threshold, 10);
+		let claim = |who| TemplateModule::claim_recovery(RuntimeOrigin::signed(who), bounded_vec("Alice"), bounded_vec("new"));
+		let held = |who| Balances::balance_on_hold(&HoldReason::RecoveryDeposit.into(), &who);
+
+		assert_noop!(TemplateModule::initiate_recovery(RuntimeOrigin::signed(4), bounded_vec("Alice")), Error::<Test>::NotRecoverable);
+		assert_noop!(set_recovery(&["Bob", "Charlie"], 0), Error::<Test>::InvalidRecoveryThreshold);
+		assert_noop!(set_recovery(&["Bob"], 2), Error::<Test>::InvalidRecoveryThreshold);
+		assert_noop!(set_recovery(&["Alice"], 1), Error::<Test>::CannotTargetSelf);
+		assert_noop!(set_recovery(&["Bob", "Bob"], 1), Error::<Test>::DuplicateFriend);
+		assert_noop!(set_recovery(&["Nobody"], 1), Error::<Test>::UserNotFound);
+		assert_ok!(set_recovery(&["Bob", "Charlie"], 2));
+		System::assert_last_event(Event::RecoverySet { name: bounded_vec("Alice"), friends: BoundedVec::truncate_from(vec![2, 3]), threshold: 2, delay: 10 }.into());
+
+		// Names are only recovered to accounts without a name, against a deposit.
+		assert_noop!(TemplateModule::initiate_recovery(RuntimeOrigin::signed(1), bounded_vec("Alice")), Error::<Test>::CannotTargetSelf);
+		assert_noop!(TemplateModule::initiate_recovery(RuntimeOrigin::signed(2), bounded_vec("Alice")), Error::<Test>::AccountHasName);
+		assert_ok!(TemplateModule::initiate_recovery(RuntimeOrigin::signed(4), bounded_vec("Alice")));
+		System::assert_last_event(Event::RecoveryInitiated { name: bounded_vec("Alice"), rescuer: 4 }.into());
+		assert_eq!(held(4), 10);
+		assert_noop!(TemplateModule::initiate_recovery(RuntimeOrigin::signed(4), bounded_vec("Alice")), Error::<Test>::RecoveryAlreadyStarted);
+		// Until a friend vouches for it, a recovery doesn't hold the owner back.
+		assert_ok!(set_recovery(&["Bob", "Charlie"], 2));
+
+		// The owner cancels recoveries they didn't ask for and gets the deposit.
+		assert_ok!(TemplateModule::initiate_recovery(RuntimeOrigin::signed(5), bounded_vec("Alice")));
+		assert_noop!(TemplateModule::cancel_recovery(RuntimeOrigin::signed(2), bounded_vec("Alice"), bounded_vec("123"), 5), Error::<Test>::NotAuthorized);
+		assert_ok!(TemplateModule::cancel_recovery(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 5));
+		System::assert_last_event(Event::RecoveryCancelled { name: bounded_vec("Alice"), rescuer: 5 }.into());
+		assert_eq!(held(5), 0);
+		assert_eq!(Balances::free_balance(5), 90);
+		assert_eq!(Balances::free_balance(1), 115);
+
+		assert_noop!(TemplateModule::vouch(RuntimeOrigin::signed(5), bounded_vec("Alice"), 4), Error::<Test>::NotFriend);
+		assert_noop!(TemplateModule::vouch(RuntimeOrigin::signed(2), bounded_vec("Alice"), 5), Error::<Test>::RecoveryNotFound);
+		assert_ok!(TemplateModule::vouch(RuntimeOrigin::signed(2), bounded_vec("Alice"), 4));
+		System::assert_last_event(Event::RecoveryVouched { name: bounded_vec("Alice"), rescuer: 4, friend: 2 }.into());
+		assert_noop!(set_recovery(&["Bob"], 1), Error::<Test>::RecoveryInProgress);
+		assert_noop!(TemplateModule::update_user_name(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Alicia")), Error::<Test>::RecoveryInProgress);
+		assert_noop!(TemplateModule::vouch(RuntimeOrigin::signed(2), bounded_vec("Alice"), 4), Error::<Test>::AlreadyVouched);
+		assert_noop!(claim(4), Error::<Test>::ThresholdNotReached);
+		assert_ok!(TemplateModule::vouch(RuntimeOrigin::signed(3), bounded_vec("Alice"), 4));
+		assert_noop!(claim(4), Error::<Test>::RecoveryDelayNotPassed);
+		assert_noop!(claim(5), Error::<Test>::RecoveryNotFound);
+
+		// After the delay the name moves to the new account with a new password, and the tweets
+		// with their tips, pins and polls go along.
+		System::set_block_number(11);
+		assert_ok!(claim(4));
+		System::assert_has_event(Event::NameRecovered { name: bounded_vec("Alice"), from: 1, to: 4 }.into());
+		assert_eq!(held(4), 0);
+		assert_eq!(TemplateModule::profile(b"Alice").unwrap().account, 4);
+		assert_eq!(Names::<Test>::get(4), Some(bounded_vec("Alice")));
+		assert!(!Names::<Test>::contains_key(1));
+		assert_eq!(TemplateModule::tips(b"Alice", 0), 5);
+		assert!(!Tips::<Test>::contains_key(1, 0));
+		assert_eq!(PinnedTweets::<Test>::get(4).into_inner(), vec![0]);
+		assert_eq!(PollsEndingAt::<Test>::get(20).into_inner(), vec![(4, 1)]);
+		assert!(TemplateModule::poll(b"Alice", 1).is_some());
+		assert_noop!(TemplateModule::create_tweet(RuntimeOrigin::signed(4), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Back")), Error::<Test>::InvalidCredentials);
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(4), bounded_vec("Alice"), bounded_vec("new"), bounded_tweet("Back")));
+		assert_noop!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("new"), bounded_tweet("Still me")), Error::<Test>::NotAuthorized);
+
+		// Friends who gave up their name can't vouch anymore.
+		assert_ok!(TemplateModule::initiate_recovery(RuntimeOrigin::signed(5), bounded_vec("Alice")));
+		assert_ok!(TemplateModule::transfer_name(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 6));
+		assert_noop!(TemplateModule::vouch(RuntimeOrigin::signed(2), bounded_vec("Alice"), 5), Error::<Test>::UserNotFound);
+		assert_ok!(TemplateModule::cancel_recovery(RuntimeOrigin::signed(4), bounded_vec("Alice"), bounded_vec("new"), 5));
+
+		// The recovery config stays with the name until its owner removes it.
+		assert_ok!(TemplateModule::remove_recovery(RuntimeOrigin::signed(4), bounded_vec("Alice"), bounded_vec("new")));
+		System::assert_last_event(Event::RecoveryRemoved { name: bounded_vec("Alice") }.into());
+		assert_noop!(TemplateModule::remove_recovery(RuntimeOrigin::signed(4), bounded_vec("Alice"), bounded_vec("new")), Error::<Test>::NotRecoverable);
+	})
+}
+
+#[test]
+fn names_are_transferred_and_sold_with_their_tweets() {
+	use crate::{ExpiringAt, HoldReason, Judgement, Judgements, NamesForSale, Recoveries, Reports, ScheduledTweets};
+	use frame_support::traits::fungible::InspectHold;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 1991, 6, 5));
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Hello")));
+		assert_ok!(TemplateModule::report_tweet(RuntimeOrigin::signed(2), bounded_vec("Alice"), 0, bounded_vec("spam")));
+		assert_ok!(TemplateModule::set_recovery(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), BoundedVec::truncate_from(vec![bounded_vec("Bob")]), 1, 10));
+		assert_ok!(TemplateModule::add_registrar(RuntimeOrigin::root(), 5));
+		assert_ok!(TemplateModule::provide_judgement(RuntimeOrigin::signed(5), bounded_vec("Alice"), Judgement::KnownGood));
+		let transfer = |who, password: &str, to| TemplateModule::transfer_name(RuntimeOrigin::signed(who), bounded_vec("Alice"), bounded_vec(password), to);
+
+		assert_noop!(transfer(2, "123", 3), Error::<Test>::NotAuthorized);
+		assert_noop!(transfer(1, "123", 1), Error::<Test>::CannotTargetSelf);
+		assert_noop!(transfer(1, "123", 2), Error::<Test>::AccountHasName);
+		assert_ok!(TemplateModule::initiate_recovery(RuntimeOrigin::signed(4), bounded_vec("Alice")));
+		assert_ok!(TemplateModule::vouch(RuntimeOrigin::signed(2), bounded_vec("Alice"), 4));
+		assert_noop!(transfer(1, "123", 3), Error::<Test>::RecoveryInProgress);
+		assert_ok!(TemplateModule::cancel_recovery(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 4));
+
+		assert_ok!(TemplateModule::schedule_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Later"), 5));
+		assert_ok!(TemplateModule::create_expiring_tweet(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Soon gone"), 20));
+
+		// The tweets and their moderation queue entries go along, while the recovery config,
+		// judgements and scheduled tweets stay behind with the previous owner.
+		assert_ok!(transfer(1, "123", 3));
+		System::assert_last_event(Event::NameTransferred { name: bounded_vec("Alice"), from: 1, to: 3 }.into());
+		assert_eq!(TemplateModule::profile(b"Alice").unwrap().account, 3);
+		assert_eq!(TemplateModule::profile(b"Alice").unwrap().tweet_count, 2);
+		assert!(Reports::<Test>::contains_key(3, 0));
+		// The expiring tweet still expires, but the previous owner gets its deposit back.
+		assert_eq!(ExpiringAt::<Test>::get(20).to_vec(), vec![(3, 1)]);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::ExpiringTweetDeposit.into(), &1), 0);
+		assert!(!Reports::<Test>::contains_key(1, 0));
+		assert!(!Recoveries::<Test>::contains_key(bounded_vec("Alice")));
+		assert!(Judgements::<Test>::get(bounded_vec("Alice")).is_empty());
+		assert!(ScheduledTweets::<Test>::get(1).is_empty());
+		assert_eq!(pallet_scheduler::Agenda::<Test>::get(5).iter().flatten().count(), 0);
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(3), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Mine now")));
+		assert_noop!(transfer(1, "123", 4), Error::<Test>::NotAuthorized);
+
+		// Names are sold at their listed price to accounts without a name.
+		let buy = |who, price| TemplateModule::buy_name(RuntimeOrigin::signed(who), bounded_vec("Alice"), price, bounded_vec("bought"));
+		assert_noop!(buy(4, 50), Error::<Test>::NotForSale);
+		assert_noop!(TemplateModule::list_name_for_sale(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 50), Error::<Test>::NotAuthorized);
+		assert_ok!(TemplateModule::list_name_for_sale(RuntimeOrigin::signed(3), bounded_vec("Alice"), bounded_vec("123"), 50));
+		System::assert_last_event(Event::NameListed { name: bounded_vec("Alice"), price: 50 }.into());
+		assert_noop!(buy(4, 40), Error::<Test>::SalePriceChanged);
+		assert_noop!(buy(2, 50), Error::<Test>::AccountHasName);
+		assert_ok!(TemplateModule::unlist_name(RuntimeOrigin::signed(3), bounded_vec("Alice"), bounded_vec("123")));
+		System::assert_last_event(Event::NameUnlisted { name: bounded_vec("Alice") }.into());
+		assert_noop!(buy(4, 50), Error::<Test>::NotForSale);
+		assert_ok!(TemplateModule::list_name_for_sale(RuntimeOrigin::signed(3), bounded_vec("Alice"), bounded_vec("123"), 50));
+		// Buying never reaps the buyer's account.
+		assert_ok!(TemplateModule::list_name_for_sale(RuntimeOrigin::signed(3), bounded_vec("Alice"), bounded_vec("123"), 90));
+		assert!(buy(4, 90).is_err());
+		assert_ok!(TemplateModule::list_name_for_sale(RuntimeOrigin::signed(3), bounded_vec("Alice"), bounded_vec("123"), 50));
+
+		assert_ok!(buy(4, 50));
+		System::assert_has_event(Event::NameSold { name: bounded_vec("Alice"), from: 3, to: 4, price: 50 }.into());
+		assert_eq!(Balances::free_balance(3), 150);
+		// Account 4 paid a recovery deposit to Alice's previous owner before.
+		assert_eq!(Balances::free_balance(4), 40);
+		assert!(!NamesForSale::<Test>::contains_key(bounded_vec("Alice")));
+		assert_eq!(TemplateModule::profile(b"Alice").unwrap().tweet_count, 3);
+		assert!(Reports::<Test>::contains_key(4, 0));
+		assert_noop!(TemplateModule::create_tweet(RuntimeOrigin::signed(4), bounded_vec("Alice"), bounded_vec("123"), bounded_tweet("Hi")), Error::<Test>::InvalidCredentials);
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(4), bounded_vec("Alice"), bounded_vec("bought"), bounded_tweet("Hi")));
+
+		// The previous owners are free to register a new name.
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(3), bounded_vec("Carol"), bounded_vec("789"), 1992, 7, 6));
+	})
+}
+
+#[test]
+fn handed_over_names_leave_blocks_mutes_and_messages_behind() {
+	use crate::{Blocked, MessagingKeys};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 1990, 5, 4));
+		assert_ok!(TemplateModule::create_user(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), 1991, 6, 5));
+		assert_ok!(TemplateModule::mute_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Bob")));
+		assert_ok!(TemplateModule::register_messaging_key(RuntimeOrigin::signed(1), [9; 32]));
+		let send = || TemplateModule::send_message(RuntimeOrigin::signed(2), bounded_vec("Alice"), BoundedVec::truncate_from(vec![7; 64]), [1; 24], [2; 32]);
+		assert_ok!(send());
+		assert_ok!(TemplateModule::block_user(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), bounded_vec("Bob")));
+
+		// The new owner starts without the previous owner's blocks, mutes, key and messages.
+		assert_ok!(TemplateModule::transfer_name(RuntimeOrigin::signed(1), bounded_vec("Alice"), bounded_vec("123"), 3));
+		assert!(Blocked::<Test>::contains_key(1, 2));
+		assert!(MessagingKeys::<Test>::contains_key(1));
+		assert!(TemplateModule::muted_names(b"Alice").is_empty());
+		assert!(TemplateModule::messages(b"Alice", b"Bob", 0, 10).is_empty());
+		assert_ok!(TemplateModule::create_tweet(RuntimeOrigin::signed(2), bounded_vec("Bob"), bounded_vec("456"), bounded_tweet("Hey @Alice!")));
+
+		// Messages to the name go to the new owner once they register a key.
+		assert_noop!(send(), Error::<Test>::NoMessagingKey);
+		assert_ok!(TemplateModule::register_messaging_key(RuntimeOrigin::signed(3), [8; 32]));
+		assert_ok!(send());
+		let messages = TemplateModule::messages(b"Alice", b"Bob", 0, 10);
+		assert_eq!(messages.len(), 1);
+		assert_eq!(messages[0].1.from, 2);
+	})
+}
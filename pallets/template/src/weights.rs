@@ -1,14 +1,14 @@
 
-//! Autogenerated weights for pallet_template
+//! Autogenerated weights for `pallet_template`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 43.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Alexs-MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
 
 // Executed Command:
-// ../../target/release/node-template
+// ./target/production/solochain-template-node
 // benchmark
 // pallet
 // --chain
@@ -17,233 +17,3427 @@
 // pallet_template
 // --extrinsic
 // *
-// --steps=50
-// --repeat=20
+// --steps
+// 50
+// --repeat
+// 20
 // --wasm-execution=compiled
 // --output
 // pallets/template/src/weights.rs
 // --template
-// ../../.maintain/frame-weight-template.hbs
+// .maintain/frame-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
+#![allow(missing_docs)]
 
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
-/// Weight functions needed for pallet_template.
+/// Weight functions needed for `pallet_template`.
 pub trait WeightInfo {
-	fn create_user() -> Weight;
-	fn verify_user() -> Weight;
-	fn create_tweet() -> Weight;
-	fn cause_error() -> Weight;
-	fn get_user_birthday() -> Weight;
-	fn get_user_tweets() -> Weight;
-	fn update_user_name() -> Weight;
-	fn update_password() -> Weight;
-	fn delete_tweet() -> Weight;
-	fn delete_user() -> Weight;
+	fn create_user(n: u32, p: u32, ) -> Weight;
+	fn verify_user(n: u32, p: u32, ) -> Weight;
+	fn create_tweet(n: u32, p: u32, c: u32, ) -> Weight;
+	fn get_user_birthday(n: u32, ) -> Weight;
+	fn get_user_tweets(n: u32, t: u32, ) -> Weight;
+	fn update_user_name(n: u32, p: u32, t: u32, ) -> Weight;
+	fn update_password(n: u32, p: u32, ) -> Weight;
+	fn delete_tweet(n: u32, p: u32, ) -> Weight;
+	fn delete_user(n: u32, p: u32, t: u32, ) -> Weight;
+	fn block_user(n: u32, p: u32, ) -> Weight;
+	fn unblock_user(n: u32, p: u32, ) -> Weight;
+	fn mute_user(n: u32, p: u32, ) -> Weight;
+	fn unmute_user(n: u32, p: u32, ) -> Weight;
+	fn report_tweet(n: u32, r: u32, ) -> Weight;
+	fn dismiss_reports(n: u32, ) -> Weight;
+	fn hide_tweet(n: u32, ) -> Weight;
+	fn remove_tweet(n: u32, ) -> Weight;
+	fn suspend_name(n: u32, ) -> Weight;
+	fn appeal(n: u32, p: u32, ) -> Weight;
+	fn resolve_appeal(n: u32, ) -> Weight;
+	fn suspend_user(n: u32, ) -> Weight;
+	fn unsuspend_user(n: u32, ) -> Weight;
+	fn ban_user(n: u32, ) -> Weight;
+	fn add_registrar() -> Weight;
+	fn remove_registrar() -> Weight;
+	fn provide_judgement(n: u32, ) -> Weight;
+	fn revoke_judgement(n: u32, ) -> Weight;
+	fn register_messaging_key() -> Weight;
+	fn send_message(n: u32, c: u32, ) -> Weight;
+	fn delete_message() -> Weight;
+	fn create_poll_tweet(n: u32, p: u32, c: u32, ) -> Weight;
+	fn vote_poll(n: u32, ) -> Weight;
+	fn close_polls(p: u32, ) -> Weight;
+	fn schedule_tweet(n: u32, p: u32, c: u32, ) -> Weight;
+	fn cancel_scheduled_tweet(n: u32, p: u32, ) -> Weight;
+	fn publish_scheduled_tweet() -> Weight;
+	fn create_expiring_tweet(n: u32, p: u32, c: u32, ) -> Weight;
+	fn expire_tweets(t: u32, ) -> Weight;
+	fn pin_tweet(n: u32, p: u32, ) -> Weight;
+	fn unpin_tweet(n: u32, p: u32, ) -> Weight;
+	fn bookmark(n: u32, ) -> Weight;
+	fn unbookmark(n: u32, ) -> Weight;
+	fn remove_stale_bookmarks(b: u32, ) -> Weight;
+	fn create_list(n: u32, p: u32, ) -> Weight;
+	fn add_to_list(n: u32, p: u32, ) -> Weight;
+	fn remove_from_list(n: u32, p: u32, ) -> Weight;
+	fn delete_list(n: u32, p: u32, ) -> Weight;
+	fn tip_tweet(n: u32, ) -> Weight;
+	fn tip_tweet_with_asset(n: u32, ) -> Weight;
+	fn set_avatar(n: u32, p: u32, ) -> Weight;
+	fn clear_avatar(n: u32, ) -> Weight;
+	fn remove_stale_avatars(a: u32, ) -> Weight;
+	fn add_device(n: u32, p: u32, ) -> Weight;
+	fn remove_device(n: u32, p: u32, ) -> Weight;
+	fn set_recovery(n: u32, p: u32, f: u32, ) -> Weight;
+	fn remove_recovery(n: u32, p: u32, ) -> Weight;
+	fn initiate_recovery(n: u32, ) -> Weight;
+	fn vouch(n: u32, ) -> Weight;
+	fn claim_recovery(n: u32, p: u32, t: u32, ) -> Weight;
+	fn cancel_recovery(n: u32, p: u32, ) -> Weight;
+	fn transfer_name(n: u32, p: u32, t: u32, ) -> Weight;
+	fn list_name_for_sale(n: u32, p: u32, ) -> Weight;
+	fn unlist_name(n: u32, p: u32, ) -> Weight;
+	fn buy_name(n: u32, p: u32, t: u32, ) -> Weight;
 }
 
-/// Weights for pallet_template using the Substrate node and recommended hardware.
+/// Weights for `pallet_template` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn create_user() -> Weight {
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:1)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Birthdays` (r:0 w:1)
+	/// Proof: `TemplateModule::Birthdays` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:0 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:0 w:1)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:0 w:1)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn create_user(n: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `42`
+		//  Estimated: `3763`
+		// Minimum execution time: 27_655_000 picoseconds.
+		Weight::from_parts(26_680_330, 3763)
+			// Standard Error: 4_378
+			.saturating_add(Weight::from_parts(31_300, 0).saturating_mul(n.into()))
+			// Standard Error: 4_378
+			.saturating_add(Weight::from_parts(9_250, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn verify_user(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 18_718_000 picoseconds.
+		Weight::from_parts(21_198_158, 3775)
+			// Standard Error: 567
+			.saturating_add(Weight::from_parts(22_861, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:0 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `c` is `[1, 280]`.
+	fn create_tweet(n: u32, p: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `294 + n * (3 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 29_907_000 picoseconds.
+		Weight::from_parts(19_000_066, 3775)
+			// Standard Error: 1_273
+			.saturating_add(Weight::from_parts(69_151, 0).saturating_mul(n.into()))
+			// Standard Error: 1_273
+			.saturating_add(Weight::from_parts(28_625, 0).saturating_mul(p.into()))
+			// Standard Error: 1_164
+			.saturating_add(Weight::from_parts(17_431, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Birthdays` (r:1 w:0)
+	/// Proof: `TemplateModule::Birthdays` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn get_user_birthday(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `234 + n * (2 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 17_268_000 picoseconds.
+		Weight::from_parts(18_384_796, 3763)
+			// Standard Error: 862
+			.saturating_add(Weight::from_parts(70_880, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:0)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn get_user_tweets(n: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (1003 ±0) + t * (568 ±0)`
+		//  Estimated: `3763 + t * (3306 ±0)`
+		// Minimum execution time: 26_229_000 picoseconds.
+		Weight::from_parts(27_008_000, 3763)
+			// Standard Error: 1_384_751
+			.saturating_add(Weight::from_parts(26_938_013, 0).saturating_mul(n.into()))
+			// Standard Error: 354_465
+			.saturating_add(Weight::from_parts(28_301_320, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 3306).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:2)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:2)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Birthdays` (r:1 w:2)
+	/// Proof: `TemplateModule::Birthdays` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:2)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:21 w:40)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ListCount` (r:1 w:2)
+	/// Proof: `TemplateModule::ListCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:1 w:2)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:0 w:1)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Judgements` (r:0 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:0 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn update_user_name(n: u32, _p: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (1008 ±0) + t * (568 ±0)`
+		//  Estimated: `127368 + t * (3306 ±0)`
+		// Minimum execution time: 444_149_000 picoseconds.
+		Weight::from_parts(456_374_000, 127368)
+			// Standard Error: 745_701
+			.saturating_add(Weight::from_parts(33_960_989, 0).saturating_mul(n.into()))
+			// Standard Error: 190_718
+			.saturating_add(Weight::from_parts(19_024_706, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(30_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(55_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 3306).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:1)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn update_password(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 25_976_000 picoseconds.
+		Weight::from_parts(28_462_084, 3775)
+			// Standard Error: 888
+			.saturating_add(Weight::from_parts(37_611, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-
-	fn verify_user() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1 w:1)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1 w:0)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:0 w:1)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:0 w:1)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:0 w:1)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:0 w:1)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn delete_tweet(n: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `509 + n * (3 ±0)`
+		//  Estimated: `4296`
+		// Minimum execution time: 97_868_000 picoseconds.
+		Weight::from_parts(108_584_674, 4296)
+			// Standard Error: 10_076
+			.saturating_add(Weight::from_parts(313_683, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:1)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:1)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1000 w:1000)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1000 w:0)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1000 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:20 w:20)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:10 w:10)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:20 w:20)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:10 w:10)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessageDepositRound` (r:1 w:1)
+	/// Proof: `TemplateModule::MessageDepositRound` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:0 w:1000)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Birthdays` (r:0 w:1)
+	/// Proof: `TemplateModule::Birthdays` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:0 w:1000)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:1000)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:0 w:1000)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:0 w:1)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Judgements` (r:0 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:0 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ListCount` (r:0 w:1)
+	/// Proof: `TemplateModule::ListCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:0 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:0 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:0 w:1000)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:0 w:1)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:0 w:1000)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn delete_user(_n: u32, p: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `12091 + n * (4 ±0) + t * (31 ±0)`
+		//  Estimated: `130370 + t * (2818 ±0)`
+		// Minimum execution time: 506_625_000 picoseconds.
+		Weight::from_parts(523_359_000, 130370)
+			// Standard Error: 2_276_329
+			.saturating_add(Weight::from_parts(20_051_476, 0).saturating_mul(p.into()))
+			// Standard Error: 582_186
+			.saturating_add(Weight::from_parts(62_620_987, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(68_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(75_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 2818).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:0 w:1)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn block_user(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352 + n * (3 ±0)`
+		//  Estimated: `6536`
+		// Minimum execution time: 40_434_000 picoseconds.
+		Weight::from_parts(61_700_004, 6536)
+			// Standard Error: 5_386
+			.saturating_add(Weight::from_parts(81_396, 0).saturating_mul(n.into()))
+			// Standard Error: 5_386
+			.saturating_add(Weight::from_parts(2_658, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-
-	fn create_tweet() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:1)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn unblock_user(n: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `454 + n * (3 ±0)`
+		//  Estimated: `6536`
+		// Minimum execution time: 52_014_000 picoseconds.
+		Weight::from_parts(73_857_927, 6536)
+			// Standard Error: 2_290
+			.saturating_add(Weight::from_parts(79_365, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Muted` (r:0 w:1)
+	/// Proof: `TemplateModule::Muted` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn mute_user(n: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		//  Measured:  `352 + n * (3 ±0)`
+		//  Estimated: `6536`
+		// Minimum execution time: 47_292_000 picoseconds.
+		Weight::from_parts(58_151_207, 6536)
+			// Standard Error: 1_127
+			.saturating_add(Weight::from_parts(97_465, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-
-	fn get_user_birthday() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Muted` (r:1 w:1)
+	/// Proof: `TemplateModule::Muted` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn unmute_user(n: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		//  Measured:  `454 + n * (3 ±0)`
+		//  Estimated: `6536`
+		// Minimum execution time: 57_404_000 picoseconds.
+		Weight::from_parts(71_211_329, 6536)
+			// Standard Error: 1_319
+			.saturating_add(Weight::from_parts(105_272, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-
-	fn get_user_tweets() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:1 w:1)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `r` is `[0, 19]`.
+	fn report_tweet(n: u32, r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		//  Measured:  `396 + n * (2 ±0) + r * (290 ±0)`
+		//  Estimated: `9318`
+		// Minimum execution time: 37_499_000 picoseconds.
+		Weight::from_parts(62_832_142, 9318)
+			// Standard Error: 4_937
+			.saturating_add(Weight::from_parts(28_050, 0).saturating_mul(n.into()))
+			// Standard Error: 64_393
+			.saturating_add(Weight::from_parts(744_996, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-
-	fn update_user_name() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:1 w:1)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn dismiss_reports(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6098 + n * (1 ±0)`
+		//  Estimated: `9318`
+		// Minimum execution time: 31_954_000 picoseconds.
+		Weight::from_parts(49_656_140, 9318)
+			// Standard Error: 3_870
+			.saturating_add(Weight::from_parts(45_373, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:0 w:1)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:0 w:1)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn hide_tweet(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		//  Measured:  `351 + n * (2 ±0)`
+		//  Estimated: `4296`
+		// Minimum execution time: 40_461_000 picoseconds.
+		Weight::from_parts(51_038_859, 4296)
+			// Standard Error: 1_576
+			.saturating_add(Weight::from_parts(45_205, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1 w:0)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1 w:0)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:0 w:1)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:0 w:1)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:0 w:1)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:0 w:1)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn remove_tweet(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `355 + n * (2 ±0)`
+		//  Estimated: `4296`
+		// Minimum execution time: 76_676_000 picoseconds.
+		Weight::from_parts(95_537_772, 4296)
+			// Standard Error: 2_547
+			.saturating_add(Weight::from_parts(49_321, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:1)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn suspend_name(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `220 + n * (1 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 29_366_000 picoseconds.
+		Weight::from_parts(34_729_558, 3763)
+			// Standard Error: 937
+			.saturating_add(Weight::from_parts(42_578, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:1 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn appeal(_n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `324 + n * (2 ±0)`
+		//  Estimated: `3776`
+		// Minimum execution time: 40_045_000 picoseconds.
+		Weight::from_parts(75_860_665, 3776)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:1 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:1)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn resolve_appeal(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `614 + n * (1 ±0)`
+		//  Estimated: `3776`
+		// Minimum execution time: 26_993_000 picoseconds.
+		Weight::from_parts(31_614_786, 3776)
+			// Standard Error: 3_895
+			.saturating_add(Weight::from_parts(29_384, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:1)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn suspend_user(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `220 + n * (1 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 20_799_000 picoseconds.
+		Weight::from_parts(25_856_763, 3763)
+			// Standard Error: 1_736
+			.saturating_add(Weight::from_parts(7_047, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:1)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn unsuspend_user(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `315 + n * (1 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 22_509_000 picoseconds.
+		Weight::from_parts(27_441_343, 3763)
+			// Standard Error: 3_135
+			.saturating_add(Weight::from_parts(28_980, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:1)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn ban_user(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `315 + n * (1 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 33_899_000 picoseconds.
+		Weight::from_parts(44_228_481, 3763)
+			// Standard Error: 1_737
+			.saturating_add(Weight::from_parts(43_462, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::Registrars` (r:1 w:1)
+	/// Proof: `TemplateModule::Registrars` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	fn add_registrar() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `678`
+		//  Estimated: `2126`
+		// Minimum execution time: 28_892_000 picoseconds.
+		Weight::from_parts(32_580_000, 2126)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-
-	fn update_password() -> Weight {
+	/// Storage: `TemplateModule::Registrars` (r:1 w:1)
+	/// Proof: `TemplateModule::Registrars` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	fn remove_registrar() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		//  Measured:  `710`
+		//  Estimated: `2126`
+		// Minimum execution time: 28_545_000 picoseconds.
+		Weight::from_parts(31_755_000, 2126)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-
-	fn delete_tweet() -> Weight {
+	/// Storage: `TemplateModule::Registrars` (r:1 w:0)
+	/// Proof: `TemplateModule::Registrars` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Judgements` (r:1 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn provide_judgement(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		//  Measured:  `1550 + n * (2 ±0)`
+		//  Estimated: `4392`
+		// Minimum execution time: 28_973_000 picoseconds.
+		Weight::from_parts(43_481_469, 4392)
+			// Standard Error: 2_147
+			.saturating_add(Weight::from_parts(84_815, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::Judgements` (r:1 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn revoke_judgement(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `922 + n * (1 ±0)`
+		//  Estimated: `4392`
+		// Minimum execution time: 20_621_000 picoseconds.
+		Weight::from_parts(24_187_056, 4392)
+			// Standard Error: 3_219
+			.saturating_add(Weight::from_parts(52_521, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-
-	fn delete_user() -> Weight {
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessagingKeys` (r:0 w:1)
+	/// Proof: `TemplateModule::MessagingKeys` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn register_messaging_key() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		//  Measured:  `480`
+		//  Estimated: `3763`
+		// Minimum execution time: 25_707_000 picoseconds.
+		Weight::from_parts(27_797_000, 3763)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:0)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessagingKeys` (r:1 w:0)
+	/// Proof: `TemplateModule::MessagingKeys` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessageCount` (r:1 w:1)
+	/// Proof: `TemplateModule::MessageCount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessageDepositRound` (r:1 w:0)
+	/// Proof: `TemplateModule::MessageDepositRound` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Messages` (r:0 w:1)
+	/// Proof: `TemplateModule::Messages` (`max_values`: None, `max_size`: Some(1222), added: 3697, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `c` is `[1, 1024]`.
+	fn send_message(_n: u32, _c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `477 + n * (2 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 91_804_000 picoseconds.
+		Weight::from_parts(132_710_850, 3763)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TemplateModule::Messages` (r:1 w:1)
+	/// Proof: `TemplateModule::Messages` (`max_values`: None, `max_size`: Some(1222), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessageCount` (r:1 w:1)
+	/// Proof: `TemplateModule::MessageCount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessageDepositRound` (r:1 w:0)
+	/// Proof: `TemplateModule::MessageDepositRound` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn delete_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1632`
+		//  Estimated: `4687`
+		// Minimum execution time: 71_801_000 picoseconds.
+		Weight::from_parts(74_784_000, 4687)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PollsEndingAt` (r:1 w:1)
+	/// Proof: `TemplateModule::PollsEndingAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NextPollIndex` (r:1 w:1)
+	/// Proof: `TemplateModule::NextPollIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:0 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:0 w:1)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `c` is `[1, 280]`.
+	fn create_poll_tweet(n: u32, p: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3895 + n * (3 ±0)`
+		//  Estimated: `7079`
+		// Minimum execution time: 59_173_000 picoseconds.
+		Weight::from_parts(52_446_950, 7079)
+			// Standard Error: 6_810
+			.saturating_add(Weight::from_parts(70_985, 0).saturating_mul(n.into()))
+			// Standard Error: 6_810
+			.saturating_add(Weight::from_parts(9_410, 0).saturating_mul(p.into()))
+			// Standard Error: 6_224
+			.saturating_add(Weight::from_parts(65_109, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:0)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1 w:1)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VoterIds` (r:1 w:1)
+	/// Proof: `TemplateModule::VoterIds` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NextVoterId` (r:1 w:1)
+	/// Proof: `TemplateModule::NextVoterId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PollVotes` (r:1 w:1)
+	/// Proof: `TemplateModule::PollVotes` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn vote_poll(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `957 + n * (1 ±0)`
+		//  Estimated: `3808`
+		// Minimum execution time: 51_354_000 picoseconds.
+		Weight::from_parts(82_680_928, 3808)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TemplateModule::PollsEndingAt` (r:1 w:1)
+	/// Proof: `TemplateModule::PollsEndingAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:100 w:100)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 100]`.
+	fn close_polls(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `583 + p * (346 ±0)`
+		//  Estimated: `7079 + p * (2818 ±0)`
+		// Minimum execution time: 10_987_000 picoseconds.
+		Weight::from_parts(95_373_143, 7079)
+			// Standard Error: 330_191
+			.saturating_add(Weight::from_parts(14_845_417, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2818).saturating_mul(p.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NextScheduleId` (r:1 w:1)
+	/// Proof: `TemplateModule::NextScheduleId` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `c` is `[1, 280]`.
+	fn schedule_tweet(n: u32, p: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6706 + n * (2 ±0)`
+		//  Estimated: `13928`
+		// Minimum execution time: 70_730_000 picoseconds.
+		Weight::from_parts(68_261_566, 13928)
+			// Standard Error: 5_894
+			.saturating_add(Weight::from_parts(62_256, 0).saturating_mul(n.into()))
+			// Standard Error: 5_894
+			.saturating_add(Weight::from_parts(45_946, 0).saturating_mul(p.into()))
+			// Standard Error: 5_387
+			.saturating_add(Weight::from_parts(21_956, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn cancel_scheduled_tweet(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7080 + n * (2 ±0)`
+		//  Estimated: `13928`
+		// Minimum execution time: 69_750_000 picoseconds.
+		Weight::from_parts(50_612_916, 13928)
+			// Standard Error: 13_843
+			.saturating_add(Weight::from_parts(155_294, 0).saturating_mul(n.into()))
+			// Standard Error: 13_843
+			.saturating_add(Weight::from_parts(181_525, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:0 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	fn publish_scheduled_tweet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7588`
+		//  Estimated: `13928`
+		// Minimum execution time: 106_780_000 picoseconds.
+		Weight::from_parts(115_031_000, 13928)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiringAt` (r:1 w:1)
+	/// Proof: `TemplateModule::ExpiringAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryCursor` (r:1 w:1)
+	/// Proof: `TemplateModule::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:0 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:0 w:1)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `c` is `[1, 280]`.
+	fn create_expiring_tweet(n: u32, p: u32, _c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3895 + n * (3 ±0)`
+		//  Estimated: `7079`
+		// Minimum execution time: 97_710_000 picoseconds.
+		Weight::from_parts(131_999_152, 7079)
+			// Standard Error: 10_661
+			.saturating_add(Weight::from_parts(206_281, 0).saturating_mul(n.into()))
+			// Standard Error: 10_661
+			.saturating_add(Weight::from_parts(101_920, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `TemplateModule::ExpiryCursor` (r:1 w:1)
+	/// Proof: `TemplateModule::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiringAt` (r:1 w:1)
+	/// Proof: `TemplateModule::ExpiringAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:100 w:100)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:100 w:100)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:100 w:0)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:100 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::StaleBookmarks` (r:1 w:0)
+	/// Proof: `TemplateModule::StaleBookmarks` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AvatarCursor` (r:1 w:1)
+	/// Proof: `TemplateModule::AvatarCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:1 w:0)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:0 w:100)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:0 w:100)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:100)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:0 w:100)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:0 w:100)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 100]`.
+	fn expire_tweets(t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1519 + t * (640 ±0)`
+		//  Estimated: `7079 + t * (3306 ±0)`
+		// Minimum execution time: 26_054_000 picoseconds.
+		Weight::from_parts(26_586_000, 7079)
+			// Standard Error: 501_214
+			.saturating_add(Weight::from_parts(126_409_436, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 3306).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn pin_tweet(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `502 + n * (3 ±0)`
+		//  Estimated: `4296`
+		// Minimum execution time: 51_502_000 picoseconds.
+		Weight::from_parts(66_484_806, 4296)
+			// Standard Error: 3_324
+			.saturating_add(Weight::from_parts(90_955, 0).saturating_mul(n.into()))
+			// Standard Error: 3_324
+			.saturating_add(Weight::from_parts(7_476, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn unpin_tweet(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `337 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 33_093_000 picoseconds.
+		Weight::from_parts(49_431_018, 3775)
+			// Standard Error: 2_294
+			.saturating_add(Weight::from_parts(75_437, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:0)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Bookmarks` (r:1 w:1)
+	/// Proof: `TemplateModule::Bookmarks` (`max_values`: None, `max_size`: Some(18042), added: 20517, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:0 w:1)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn bookmark(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `18486 + n * (2 ±0)`
+		//  Estimated: `21507`
+		// Minimum execution time: 63_192_000 picoseconds.
+		Weight::from_parts(108_645_466, 21507)
+			// Standard Error: 6_529
+			.saturating_add(Weight::from_parts(3_526, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Bookmarks` (r:1 w:1)
+	/// Proof: `TemplateModule::Bookmarks` (`max_values`: None, `max_size`: Some(18042), added: 20517, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:0 w:1)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn unbookmark(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `18321 + n * (1 ±0)`
+		//  Estimated: `21507`
+		// Minimum execution time: 41_194_000 picoseconds.
+		Weight::from_parts(63_352_431, 21507)
+			// Standard Error: 3_586
+			.saturating_add(Weight::from_parts(62_088, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::ExpiryCursor` (r:1 w:0)
+	/// Proof: `TemplateModule::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::StaleBookmarks` (r:2 w:1)
+	/// Proof: `TemplateModule::StaleBookmarks` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:101 w:100)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Bookmarks` (r:100 w:100)
+	/// Proof: `TemplateModule::Bookmarks` (`max_values`: None, `max_size`: Some(18042), added: 20517, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AvatarCursor` (r:1 w:1)
+	/// Proof: `TemplateModule::AvatarCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:1 w:0)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 100]`.
+	fn remove_stale_bookmarks(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198 + b * (18098 ±0)`
+		//  Estimated: `6028 + b * (20517 ±0)`
+		// Minimum execution time: 37_095_000 picoseconds.
+		Weight::from_parts(29_884_582, 6028)
+			// Standard Error: 295_849
+			.saturating_add(Weight::from_parts(37_913_299, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 20517).saturating_mul(b.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:1 w:1)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ListCount` (r:1 w:1)
+	/// Proof: `TemplateModule::ListCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn create_list(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297 + n * (3 ±0)`
+		//  Estimated: `7008`
+		// Minimum execution time: 43_064_000 picoseconds.
+		Weight::from_parts(52_150_961, 7008)
+			// Standard Error: 6_086
+			.saturating_add(Weight::from_parts(77_488, 0).saturating_mul(n.into()))
+			// Standard Error: 6_086
+			.saturating_add(Weight::from_parts(12_004, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:1 w:1)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn add_to_list(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3631 + n * (4 ±0)`
+		//  Estimated: `7008`
+		// Minimum execution time: 51_381_000 picoseconds.
+		Weight::from_parts(74_023_678, 7008)
+			// Standard Error: 2_413
+			.saturating_add(Weight::from_parts(98_248, 0).saturating_mul(n.into()))
+			// Standard Error: 2_413
+			.saturating_add(Weight::from_parts(7_304, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:1 w:1)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn remove_from_list(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3663 + n * (4 ±0)`
+		//  Estimated: `7008`
+		// Minimum execution time: 45_533_000 picoseconds.
+		Weight::from_parts(62_949_419, 7008)
+			// Standard Error: 6_777
+			.saturating_add(Weight::from_parts(124_872, 0).saturating_mul(n.into()))
+			// Standard Error: 6_777
+			.saturating_add(Weight::from_parts(5_505, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:1 w:1)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ListCount` (r:1 w:1)
+	/// Proof: `TemplateModule::ListCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn delete_list(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4090 + n * (4 ±0)`
+		//  Estimated: `7008`
+		// Minimum execution time: 56_416_000 picoseconds.
+		Weight::from_parts(109_206_555, 7008)
+			// Standard Error: 7_655
+			.saturating_add(Weight::from_parts(2_758, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:0)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:1 w:1)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn tip_tweet(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `332 + n * (3 ±0)`
+		//  Estimated: `4296`
+		// Minimum execution time: 83_290_000 picoseconds.
+		Weight::from_parts(118_828_743, 4296)
+			// Standard Error: 12_260
+			.saturating_add(Weight::from_parts(87_930, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:0)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:1 w:1)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn tip_tweet_with_asset(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1050 + n * (2 ±0)`
+		//  Estimated: `6208`
+		// Minimum execution time: 92_700_000 picoseconds.
+		Weight::from_parts(106_737_523, 6208)
+			// Standard Error: 12_807
+			.saturating_add(Weight::from_parts(188_395, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:0 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn set_avatar(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `572 + n * (2 ±0)`
+		//  Estimated: `4326`
+		// Minimum execution time: 34_162_000 picoseconds.
+		Weight::from_parts(40_715_473, 4326)
+			// Standard Error: 3_500
+			.saturating_add(Weight::from_parts(19_973, 0).saturating_mul(n.into()))
+			// Standard Error: 3_500
+			.saturating_add(Weight::from_parts(17_564, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:1 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:1 w:0)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn clear_avatar(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `600 + n * (1 ±0)`
+		//  Estimated: `4326`
+		// Minimum execution time: 37_465_000 picoseconds.
+		Weight::from_parts(60_895_556, 4326)
+			// Standard Error: 5_303
+			.saturating_add(Weight::from_parts(45_270, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::ExpiryCursor` (r:1 w:0)
+	/// Proof: `TemplateModule::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::StaleBookmarks` (r:1 w:0)
+	/// Proof: `TemplateModule::StaleBookmarks` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AvatarCursor` (r:1 w:1)
+	/// Proof: `TemplateModule::AvatarCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:51 w:50)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:50 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 50]`.
+	fn remove_stale_avatars(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `396 + a * (361 ±0)`
+		//  Estimated: `4326 + a * (2773 ±0)`
+		// Minimum execution time: 12_756_000 picoseconds.
+		Weight::from_parts(71_634_933, 4326)
+			// Standard Error: 310_648
+			.saturating_add(Weight::from_parts(26_749_046, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2773).saturating_mul(a.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:1 w:1)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:1 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn add_device(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `654 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 39_058_000 picoseconds.
+		Weight::from_parts(67_473_119, 3775)
+			// Standard Error: 4_595
+			.saturating_add(Weight::from_parts(50_334, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:1 w:1)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:1 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn remove_device(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `704 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 39_361_000 picoseconds.
+		Weight::from_parts(55_857_788, 3775)
+			// Standard Error: 3_939
+			.saturating_add(Weight::from_parts(89_132, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:10 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:0 w:1)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `f` is `[1, 9]`.
+	fn set_recovery(n: u32, _p: u32, f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214 + f * (338 ±0) + n * (2 ±0)`
+		//  Estimated: `3775 + f * (2773 ±0)`
+		// Minimum execution time: 77_159_000 picoseconds.
+		Weight::from_parts(60_564_383, 3775)
+			// Standard Error: 7_758
+			.saturating_add(Weight::from_parts(111_909, 0).saturating_mul(n.into()))
+			// Standard Error: 229_460
+			.saturating_add(Weight::from_parts(13_397_064, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2773).saturating_mul(f.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn remove_recovery(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `593 + n * (3 ±0)`
+		//  Estimated: `4028`
+		// Minimum execution time: 46_624_000 picoseconds.
+		Weight::from_parts(54_174_103, 4028)
+			// Standard Error: 1_326
+			.saturating_add(Weight::from_parts(89_633, 0).saturating_mul(n.into()))
+			// Standard Error: 1_326
+			.saturating_add(Weight::from_parts(6_303, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::ActiveRecoveries` (`max_values`: None, `max_size`: Some(615), added: 3090, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn initiate_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `342 + n * (2 ±0)`
+		//  Estimated: `4080`
+		// Minimum execution time: 107_390_000 picoseconds.
+		Weight::from_parts(128_743_016, 4080)
+			// Standard Error: 3_644
+			.saturating_add(Weight::from_parts(71_739, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::Recoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::ActiveRecoveries` (`max_values`: None, `max_size`: Some(615), added: 3090, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn vouch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `970 + n * (2 ±0)`
+		//  Estimated: `4080`
+		// Minimum execution time: 46_724_000 picoseconds.
+		Weight::from_parts(54_007_599, 4080)
+			// Standard Error: 1_732
+			.saturating_add(Weight::from_parts(66_947, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::Recoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::ActiveRecoveries` (`max_values`: None, `max_size`: Some(615), added: 3090, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:2)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:2 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:1)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:0)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:1000 w:0)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:1001 w:0)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PollsEndingAt` (r:1000 w:1000)
+	/// Proof: `TemplateModule::PollsEndingAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:1000 w:0)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1000 w:1000)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1000 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:0)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VoterIds` (r:1 w:0)
+	/// Proof: `TemplateModule::VoterIds` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:10 w:10)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:20 w:20)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:10 w:10)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:0 w:1)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:0 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:0 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:0 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn claim_recovery(_n: u32, _p: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (1005 ±0) + t * (998 ±0)`
+		//  Estimated: `130370 + t * (8328 ±0)`
+		// Minimum execution time: 750_071_000 picoseconds.
+		Weight::from_parts(10_442_956_146, 130370)
+			// Standard Error: 2_202_758
+			.saturating_add(Weight::from_parts(119_442_435, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(55_u64))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(53_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 8328).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::ActiveRecoveries` (`max_values`: None, `max_size`: Some(615), added: 3090, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn cancel_recovery(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `545 + n * (3 ±0)`
+		//  Estimated: `4080`
+		// Minimum execution time: 67_927_000 picoseconds.
+		Weight::from_parts(108_090_191, 4080)
+			// Standard Error: 8_598
+			.saturating_add(Weight::from_parts(99_229, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:1)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:2)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:2 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:0)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:1000 w:0)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:1001 w:0)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PollsEndingAt` (r:1000 w:1000)
+	/// Proof: `TemplateModule::PollsEndingAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:1000 w:0)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1000 w:1000)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1000 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:0)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VoterIds` (r:1 w:0)
+	/// Proof: `TemplateModule::VoterIds` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:10 w:10)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:20 w:20)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:10 w:10)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Judgements` (r:0 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:0 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:0 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:0 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:0 w:1)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn transfer_name(n: u32, _p: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (1004 ±0) + t * (998 ±0)`
+		//  Estimated: `130370 + t * (8328 ±0)`
+		// Minimum execution time: 479_708_000 picoseconds.
+		Weight::from_parts(487_163_000, 130370)
+			// Standard Error: 5_505_418
+			.saturating_add(Weight::from_parts(53_741_383, 0).saturating_mul(n.into()))
+			// Standard Error: 1_408_047
+			.saturating_add(Weight::from_parts(130_867_178, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(52_u64))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(50_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 8328).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:0 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn list_name_for_sale(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 25_331_000 picoseconds.
+		Weight::from_parts(24_016_753, 3775)
+			// Standard Error: 790
+			.saturating_add(Weight::from_parts(43_838, 0).saturating_mul(n.into()))
+			// Standard Error: 790
+			.saturating_add(Weight::from_parts(11_184, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:1 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn unlist_name(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `309 + n * (3 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 26_938_000 picoseconds.
+		Weight::from_parts(26_535_656, 3775)
+			// Standard Error: 2_668
+			.saturating_add(Weight::from_parts(62_758, 0).saturating_mul(n.into()))
+			// Standard Error: 2_668
+			.saturating_add(Weight::from_parts(4_052, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::NamesForSale` (r:1 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:1)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:2)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:2 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:0)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:1000 w:0)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:1001 w:0)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PollsEndingAt` (r:1000 w:1000)
+	/// Proof: `TemplateModule::PollsEndingAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:1000 w:0)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1000 w:1000)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1000 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:0)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VoterIds` (r:1 w:0)
+	/// Proof: `TemplateModule::VoterIds` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:10 w:10)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:20 w:20)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:10 w:10)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:0 w:1)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Judgements` (r:0 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:0 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:0 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:0 w:1)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn buy_name(_n: u32, _p: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (1004 ±0) + t * (998 ±0)`
+		//  Estimated: `130370 + t * (8328 ±0)`
+		// Minimum execution time: 511_441_000 picoseconds.
+		Weight::from_parts(16_828_834_714, 130370)
+			// Standard Error: 1_654_184
+			.saturating_add(Weight::from_parts(125_949_233, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(53_u64))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(52_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 8328).saturating_mul(t.into()))
+	}
 }
 
-// For backwards compatibility and tests
+// For backwards compatibility and tests.
 impl WeightInfo for () {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn create_user() -> Weight {
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:1)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Birthdays` (r:0 w:1)
+	/// Proof: `TemplateModule::Birthdays` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:0 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:0 w:1)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:0 w:1)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn create_user(n: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `42`
+		//  Estimated: `3763`
+		// Minimum execution time: 27_655_000 picoseconds.
+		Weight::from_parts(26_680_330, 3763)
+			// Standard Error: 4_378
+			.saturating_add(Weight::from_parts(31_300, 0).saturating_mul(n.into()))
+			// Standard Error: 4_378
+			.saturating_add(Weight::from_parts(9_250, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn verify_user(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 18_718_000 picoseconds.
+		Weight::from_parts(21_198_158, 3775)
+			// Standard Error: 567
+			.saturating_add(Weight::from_parts(22_861, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:0 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `c` is `[1, 280]`.
+	fn create_tweet(n: u32, p: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `294 + n * (3 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 29_907_000 picoseconds.
+		Weight::from_parts(19_000_066, 3775)
+			// Standard Error: 1_273
+			.saturating_add(Weight::from_parts(69_151, 0).saturating_mul(n.into()))
+			// Standard Error: 1_273
+			.saturating_add(Weight::from_parts(28_625, 0).saturating_mul(p.into()))
+			// Standard Error: 1_164
+			.saturating_add(Weight::from_parts(17_431, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Birthdays` (r:1 w:0)
+	/// Proof: `TemplateModule::Birthdays` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn get_user_birthday(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `234 + n * (2 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 17_268_000 picoseconds.
+		Weight::from_parts(18_384_796, 3763)
+			// Standard Error: 862
+			.saturating_add(Weight::from_parts(70_880, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:0)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn get_user_tweets(n: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (1003 ±0) + t * (568 ±0)`
+		//  Estimated: `3763 + t * (3306 ±0)`
+		// Minimum execution time: 26_229_000 picoseconds.
+		Weight::from_parts(27_008_000, 3763)
+			// Standard Error: 1_384_751
+			.saturating_add(Weight::from_parts(26_938_013, 0).saturating_mul(n.into()))
+			// Standard Error: 354_465
+			.saturating_add(Weight::from_parts(28_301_320, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 3306).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:2)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:2)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Birthdays` (r:1 w:2)
+	/// Proof: `TemplateModule::Birthdays` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:2)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:21 w:40)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ListCount` (r:1 w:2)
+	/// Proof: `TemplateModule::ListCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:1 w:2)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:0 w:1)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Judgements` (r:0 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:0 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn update_user_name(n: u32, _p: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (1008 ±0) + t * (568 ±0)`
+		//  Estimated: `127368 + t * (3306 ±0)`
+		// Minimum execution time: 444_149_000 picoseconds.
+		Weight::from_parts(456_374_000, 127368)
+			// Standard Error: 745_701
+			.saturating_add(Weight::from_parts(33_960_989, 0).saturating_mul(n.into()))
+			// Standard Error: 190_718
+			.saturating_add(Weight::from_parts(19_024_706, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(30_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(55_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 3306).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:1)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn update_password(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 25_976_000 picoseconds.
+		Weight::from_parts(28_462_084, 3775)
+			// Standard Error: 888
+			.saturating_add(Weight::from_parts(37_611, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-
-	fn verify_user() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1 w:1)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1 w:0)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:0 w:1)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:0 w:1)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:0 w:1)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:0 w:1)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn delete_tweet(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `509 + n * (3 ±0)`
+		//  Estimated: `4296`
+		// Minimum execution time: 97_868_000 picoseconds.
+		Weight::from_parts(108_584_674, 4296)
+			// Standard Error: 10_076
+			.saturating_add(Weight::from_parts(313_683, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:1)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:1)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1000 w:1000)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1000 w:0)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1000 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:20 w:20)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:10 w:10)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:20 w:20)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:10 w:10)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessageDepositRound` (r:1 w:1)
+	/// Proof: `TemplateModule::MessageDepositRound` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:0 w:1000)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Birthdays` (r:0 w:1)
+	/// Proof: `TemplateModule::Birthdays` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:0 w:1000)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:1000)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:0 w:1000)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:0 w:1)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Judgements` (r:0 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:0 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ListCount` (r:0 w:1)
+	/// Proof: `TemplateModule::ListCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:0 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:0 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:0 w:1000)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:0 w:1)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:0 w:1000)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn delete_user(_n: u32, p: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `12091 + n * (4 ±0) + t * (31 ±0)`
+		//  Estimated: `130370 + t * (2818 ±0)`
+		// Minimum execution time: 506_625_000 picoseconds.
+		Weight::from_parts(523_359_000, 130370)
+			// Standard Error: 2_276_329
+			.saturating_add(Weight::from_parts(20_051_476, 0).saturating_mul(p.into()))
+			// Standard Error: 582_186
+			.saturating_add(Weight::from_parts(62_620_987, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(68_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(75_u64))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 2818).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:0 w:1)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn block_user(n: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `352 + n * (3 ±0)`
+		//  Estimated: `6536`
+		// Minimum execution time: 40_434_000 picoseconds.
+		Weight::from_parts(61_700_004, 6536)
+			// Standard Error: 5_386
+			.saturating_add(Weight::from_parts(81_396, 0).saturating_mul(n.into()))
+			// Standard Error: 5_386
+			.saturating_add(Weight::from_parts(2_658, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-
-	fn create_tweet() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:1)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn unblock_user(n: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `454 + n * (3 ±0)`
+		//  Estimated: `6536`
+		// Minimum execution time: 52_014_000 picoseconds.
+		Weight::from_parts(73_857_927, 6536)
+			// Standard Error: 2_290
+			.saturating_add(Weight::from_parts(79_365, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Muted` (r:0 w:1)
+	/// Proof: `TemplateModule::Muted` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn mute_user(n: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `352 + n * (3 ±0)`
+		//  Estimated: `6536`
+		// Minimum execution time: 47_292_000 picoseconds.
+		Weight::from_parts(58_151_207, 6536)
+			// Standard Error: 1_127
+			.saturating_add(Weight::from_parts(97_465, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-
-	fn get_user_birthday() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Muted` (r:1 w:1)
+	/// Proof: `TemplateModule::Muted` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn unmute_user(n: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `454 + n * (3 ±0)`
+		//  Estimated: `6536`
+		// Minimum execution time: 57_404_000 picoseconds.
+		Weight::from_parts(71_211_329, 6536)
+			// Standard Error: 1_319
+			.saturating_add(Weight::from_parts(105_272, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-
-	fn get_user_tweets() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:1 w:1)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `r` is `[0, 19]`.
+	fn report_tweet(n: u32, r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `396 + n * (2 ±0) + r * (290 ±0)`
+		//  Estimated: `9318`
+		// Minimum execution time: 37_499_000 picoseconds.
+		Weight::from_parts(62_832_142, 9318)
+			// Standard Error: 4_937
+			.saturating_add(Weight::from_parts(28_050, 0).saturating_mul(n.into()))
+			// Standard Error: 64_393
+			.saturating_add(Weight::from_parts(744_996, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-
-	fn update_user_name() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:1 w:1)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn dismiss_reports(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `6098 + n * (1 ±0)`
+		//  Estimated: `9318`
+		// Minimum execution time: 31_954_000 picoseconds.
+		Weight::from_parts(49_656_140, 9318)
+			// Standard Error: 3_870
+			.saturating_add(Weight::from_parts(45_373, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-
-	fn update_password() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:0 w:1)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:0 w:1)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn hide_tweet(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351 + n * (2 ±0)`
+		//  Estimated: `4296`
+		// Minimum execution time: 40_461_000 picoseconds.
+		Weight::from_parts(51_038_859, 4296)
+			// Standard Error: 1_576
+			.saturating_add(Weight::from_parts(45_205, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1 w:0)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1 w:0)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:0 w:1)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:0 w:1)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:0 w:1)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:0 w:1)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn remove_tweet(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `355 + n * (2 ±0)`
+		//  Estimated: `4296`
+		// Minimum execution time: 76_676_000 picoseconds.
+		Weight::from_parts(95_537_772, 4296)
+			// Standard Error: 2_547
+			.saturating_add(Weight::from_parts(49_321, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:1)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn suspend_name(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `220 + n * (1 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 29_366_000 picoseconds.
+		Weight::from_parts(34_729_558, 3763)
+			// Standard Error: 937
+			.saturating_add(Weight::from_parts(42_578, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-
-	fn delete_tweet() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:1 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn appeal(_n: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `324 + n * (2 ±0)`
+		//  Estimated: `3776`
+		// Minimum execution time: 40_045_000 picoseconds.
+		Weight::from_parts(75_860_665, 3776)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-
-	fn delete_user() -> Weight {
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:1 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:1)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn resolve_appeal(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `614 + n * (1 ±0)`
+		//  Estimated: `3776`
+		// Minimum execution time: 26_993_000 picoseconds.
+		Weight::from_parts(31_614_786, 3776)
+			// Standard Error: 3_895
+			.saturating_add(Weight::from_parts(29_384, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:1)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn suspend_user(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `220 + n * (1 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 20_799_000 picoseconds.
+		Weight::from_parts(25_856_763, 3763)
+			// Standard Error: 1_736
+			.saturating_add(Weight::from_parts(7_047, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-
-
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:1)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn unsuspend_user(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `315 + n * (1 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 22_509_000 picoseconds.
+		Weight::from_parts(27_441_343, 3763)
+			// Standard Error: 3_135
+			.saturating_add(Weight::from_parts(28_980, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:1)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:1)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn ban_user(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `315 + n * (1 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 33_899_000 picoseconds.
+		Weight::from_parts(44_228_481, 3763)
+			// Standard Error: 1_737
+			.saturating_add(Weight::from_parts(43_462, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::Registrars` (r:1 w:1)
+	/// Proof: `TemplateModule::Registrars` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	fn add_registrar() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `678`
+		//  Estimated: `2126`
+		// Minimum execution time: 28_892_000 picoseconds.
+		Weight::from_parts(32_580_000, 2126)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::Registrars` (r:1 w:1)
+	/// Proof: `TemplateModule::Registrars` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	fn remove_registrar() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `710`
+		//  Estimated: `2126`
+		// Minimum execution time: 28_545_000 picoseconds.
+		Weight::from_parts(31_755_000, 2126)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::Registrars` (r:1 w:0)
+	/// Proof: `TemplateModule::Registrars` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Judgements` (r:1 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn provide_judgement(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1550 + n * (2 ±0)`
+		//  Estimated: `4392`
+		// Minimum execution time: 28_973_000 picoseconds.
+		Weight::from_parts(43_481_469, 4392)
+			// Standard Error: 2_147
+			.saturating_add(Weight::from_parts(84_815, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::Judgements` (r:1 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn revoke_judgement(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `922 + n * (1 ±0)`
+		//  Estimated: `4392`
+		// Minimum execution time: 20_621_000 picoseconds.
+		Weight::from_parts(24_187_056, 4392)
+			// Standard Error: 3_219
+			.saturating_add(Weight::from_parts(52_521, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessagingKeys` (r:0 w:1)
+	/// Proof: `TemplateModule::MessagingKeys` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn register_messaging_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `480`
+		//  Estimated: `3763`
+		// Minimum execution time: 25_707_000 picoseconds.
+		Weight::from_parts(27_797_000, 3763)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:0)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessagingKeys` (r:1 w:0)
+	/// Proof: `TemplateModule::MessagingKeys` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessageCount` (r:1 w:1)
+	/// Proof: `TemplateModule::MessageCount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessageDepositRound` (r:1 w:0)
+	/// Proof: `TemplateModule::MessageDepositRound` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Messages` (r:0 w:1)
+	/// Proof: `TemplateModule::Messages` (`max_values`: None, `max_size`: Some(1222), added: 3697, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `c` is `[1, 1024]`.
+	fn send_message(_n: u32, _c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `477 + n * (2 ±0)`
+		//  Estimated: `3763`
+		// Minimum execution time: 91_804_000 picoseconds.
+		Weight::from_parts(132_710_850, 3763)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TemplateModule::Messages` (r:1 w:1)
+	/// Proof: `TemplateModule::Messages` (`max_values`: None, `max_size`: Some(1222), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessageCount` (r:1 w:1)
+	/// Proof: `TemplateModule::MessageCount` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::MessageDepositRound` (r:1 w:0)
+	/// Proof: `TemplateModule::MessageDepositRound` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn delete_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1632`
+		//  Estimated: `4687`
+		// Minimum execution time: 71_801_000 picoseconds.
+		Weight::from_parts(74_784_000, 4687)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PollsEndingAt` (r:1 w:1)
+	/// Proof: `TemplateModule::PollsEndingAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NextPollIndex` (r:1 w:1)
+	/// Proof: `TemplateModule::NextPollIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:0 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:0 w:1)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `c` is `[1, 280]`.
+	fn create_poll_tweet(n: u32, p: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3895 + n * (3 ±0)`
+		//  Estimated: `7079`
+		// Minimum execution time: 59_173_000 picoseconds.
+		Weight::from_parts(52_446_950, 7079)
+			// Standard Error: 6_810
+			.saturating_add(Weight::from_parts(70_985, 0).saturating_mul(n.into()))
+			// Standard Error: 6_810
+			.saturating_add(Weight::from_parts(9_410, 0).saturating_mul(p.into()))
+			// Standard Error: 6_224
+			.saturating_add(Weight::from_parts(65_109, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:0)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1 w:1)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VoterIds` (r:1 w:1)
+	/// Proof: `TemplateModule::VoterIds` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NextVoterId` (r:1 w:1)
+	/// Proof: `TemplateModule::NextVoterId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PollVotes` (r:1 w:1)
+	/// Proof: `TemplateModule::PollVotes` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn vote_poll(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `957 + n * (1 ±0)`
+		//  Estimated: `3808`
+		// Minimum execution time: 51_354_000 picoseconds.
+		Weight::from_parts(82_680_928, 3808)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TemplateModule::PollsEndingAt` (r:1 w:1)
+	/// Proof: `TemplateModule::PollsEndingAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:100 w:100)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 100]`.
+	fn close_polls(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `583 + p * (346 ±0)`
+		//  Estimated: `7079 + p * (2818 ±0)`
+		// Minimum execution time: 10_987_000 picoseconds.
+		Weight::from_parts(95_373_143, 7079)
+			// Standard Error: 330_191
+			.saturating_add(Weight::from_parts(14_845_417, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2818).saturating_mul(p.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NextScheduleId` (r:1 w:1)
+	/// Proof: `TemplateModule::NextScheduleId` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `c` is `[1, 280]`.
+	fn schedule_tweet(n: u32, p: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6706 + n * (2 ±0)`
+		//  Estimated: `13928`
+		// Minimum execution time: 70_730_000 picoseconds.
+		Weight::from_parts(68_261_566, 13928)
+			// Standard Error: 5_894
+			.saturating_add(Weight::from_parts(62_256, 0).saturating_mul(n.into()))
+			// Standard Error: 5_894
+			.saturating_add(Weight::from_parts(45_946, 0).saturating_mul(p.into()))
+			// Standard Error: 5_387
+			.saturating_add(Weight::from_parts(21_956, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn cancel_scheduled_tweet(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7080 + n * (2 ±0)`
+		//  Estimated: `13928`
+		// Minimum execution time: 69_750_000 picoseconds.
+		Weight::from_parts(50_612_916, 13928)
+			// Standard Error: 13_843
+			.saturating_add(Weight::from_parts(155_294, 0).saturating_mul(n.into()))
+			// Standard Error: 13_843
+			.saturating_add(Weight::from_parts(181_525, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:0 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	fn publish_scheduled_tweet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7588`
+		//  Estimated: `13928`
+		// Minimum execution time: 106_780_000 picoseconds.
+		Weight::from_parts(115_031_000, 13928)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:1)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiringAt` (r:1 w:1)
+	/// Proof: `TemplateModule::ExpiringAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryCursor` (r:1 w:1)
+	/// Proof: `TemplateModule::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:0 w:1)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:0 w:1)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `c` is `[1, 280]`.
+	fn create_expiring_tweet(n: u32, p: u32, _c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3895 + n * (3 ±0)`
+		//  Estimated: `7079`
+		// Minimum execution time: 97_710_000 picoseconds.
+		Weight::from_parts(131_999_152, 7079)
+			// Standard Error: 10_661
+			.saturating_add(Weight::from_parts(206_281, 0).saturating_mul(n.into()))
+			// Standard Error: 10_661
+			.saturating_add(Weight::from_parts(101_920, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `TemplateModule::ExpiryCursor` (r:1 w:1)
+	/// Proof: `TemplateModule::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiringAt` (r:1 w:1)
+	/// Proof: `TemplateModule::ExpiringAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:100 w:100)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:100 w:100)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:100 w:0)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:100 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::StaleBookmarks` (r:1 w:0)
+	/// Proof: `TemplateModule::StaleBookmarks` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AvatarCursor` (r:1 w:1)
+	/// Proof: `TemplateModule::AvatarCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:1 w:0)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:0 w:100)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:0 w:100)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:0 w:100)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:0 w:100)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:0 w:100)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 100]`.
+	fn expire_tweets(t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1519 + t * (640 ±0)`
+		//  Estimated: `7079 + t * (3306 ±0)`
+		// Minimum execution time: 26_054_000 picoseconds.
+		Weight::from_parts(26_586_000, 7079)
+			// Standard Error: 501_214
+			.saturating_add(Weight::from_parts(126_409_436, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 3306).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn pin_tweet(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `502 + n * (3 ±0)`
+		//  Estimated: `4296`
+		// Minimum execution time: 51_502_000 picoseconds.
+		Weight::from_parts(66_484_806, 4296)
+			// Standard Error: 3_324
+			.saturating_add(Weight::from_parts(90_955, 0).saturating_mul(n.into()))
+			// Standard Error: 3_324
+			.saturating_add(Weight::from_parts(7_476, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn unpin_tweet(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `337 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 33_093_000 picoseconds.
+		Weight::from_parts(49_431_018, 3775)
+			// Standard Error: 2_294
+			.saturating_add(Weight::from_parts(75_437, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:0)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Bookmarks` (r:1 w:1)
+	/// Proof: `TemplateModule::Bookmarks` (`max_values`: None, `max_size`: Some(18042), added: 20517, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:0 w:1)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn bookmark(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `18486 + n * (2 ±0)`
+		//  Estimated: `21507`
+		// Minimum execution time: 63_192_000 picoseconds.
+		Weight::from_parts(108_645_466, 21507)
+			// Standard Error: 6_529
+			.saturating_add(Weight::from_parts(3_526, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Bookmarks` (r:1 w:1)
+	/// Proof: `TemplateModule::Bookmarks` (`max_values`: None, `max_size`: Some(18042), added: 20517, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:0 w:1)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn unbookmark(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `18321 + n * (1 ±0)`
+		//  Estimated: `21507`
+		// Minimum execution time: 41_194_000 picoseconds.
+		Weight::from_parts(63_352_431, 21507)
+			// Standard Error: 3_586
+			.saturating_add(Weight::from_parts(62_088, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::ExpiryCursor` (r:1 w:0)
+	/// Proof: `TemplateModule::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::StaleBookmarks` (r:2 w:1)
+	/// Proof: `TemplateModule::StaleBookmarks` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:101 w:100)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Bookmarks` (r:100 w:100)
+	/// Proof: `TemplateModule::Bookmarks` (`max_values`: None, `max_size`: Some(18042), added: 20517, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AvatarCursor` (r:1 w:1)
+	/// Proof: `TemplateModule::AvatarCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:1 w:0)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[0, 100]`.
+	fn remove_stale_bookmarks(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198 + b * (18098 ±0)`
+		//  Estimated: `6028 + b * (20517 ±0)`
+		// Minimum execution time: 37_095_000 picoseconds.
+		Weight::from_parts(29_884_582, 6028)
+			// Standard Error: 295_849
+			.saturating_add(Weight::from_parts(37_913_299, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 20517).saturating_mul(b.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:1 w:1)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ListCount` (r:1 w:1)
+	/// Proof: `TemplateModule::ListCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn create_list(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297 + n * (3 ±0)`
+		//  Estimated: `7008`
+		// Minimum execution time: 43_064_000 picoseconds.
+		Weight::from_parts(52_150_961, 7008)
+			// Standard Error: 6_086
+			.saturating_add(Weight::from_parts(77_488, 0).saturating_mul(n.into()))
+			// Standard Error: 6_086
+			.saturating_add(Weight::from_parts(12_004, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:1 w:1)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn add_to_list(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3631 + n * (4 ±0)`
+		//  Estimated: `7008`
+		// Minimum execution time: 51_381_000 picoseconds.
+		Weight::from_parts(74_023_678, 7008)
+			// Standard Error: 2_413
+			.saturating_add(Weight::from_parts(98_248, 0).saturating_mul(n.into()))
+			// Standard Error: 2_413
+			.saturating_add(Weight::from_parts(7_304, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:2 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:1 w:1)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn remove_from_list(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3663 + n * (4 ±0)`
+		//  Estimated: `7008`
+		// Minimum execution time: 45_533_000 picoseconds.
+		Weight::from_parts(62_949_419, 7008)
+			// Standard Error: 6_777
+			.saturating_add(Weight::from_parts(124_872, 0).saturating_mul(n.into()))
+			// Standard Error: 6_777
+			.saturating_add(Weight::from_parts(5_505, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Lists` (r:1 w:1)
+	/// Proof: `TemplateModule::Lists` (`max_values`: None, `max_size`: Some(3543), added: 6018, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ListCount` (r:1 w:1)
+	/// Proof: `TemplateModule::ListCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn delete_list(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4090 + n * (4 ±0)`
+		//  Estimated: `7008`
+		// Minimum execution time: 56_416_000 picoseconds.
+		Weight::from_parts(109_206_555, 7008)
+			// Standard Error: 7_655
+			.saturating_add(Weight::from_parts(2_758, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:0)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:1 w:1)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn tip_tweet(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `332 + n * (3 ±0)`
+		//  Estimated: `4296`
+		// Minimum execution time: 83_290_000 picoseconds.
+		Weight::from_parts(118_828_743, 4296)
+			// Standard Error: 12_260
+			.saturating_add(Weight::from_parts(87_930, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Blocked` (r:1 w:0)
+	/// Proof: `TemplateModule::Blocked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:1 w:1)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn tip_tweet_with_asset(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1050 + n * (2 ±0)`
+		//  Estimated: `6208`
+		// Minimum execution time: 92_700_000 picoseconds.
+		Weight::from_parts(106_737_523, 6208)
+			// Standard Error: 12_807
+			.saturating_add(Weight::from_parts(188_395, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:0 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn set_avatar(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `572 + n * (2 ±0)`
+		//  Estimated: `4326`
+		// Minimum execution time: 34_162_000 picoseconds.
+		Weight::from_parts(40_715_473, 4326)
+			// Standard Error: 3_500
+			.saturating_add(Weight::from_parts(19_973, 0).saturating_mul(n.into()))
+			// Standard Error: 3_500
+			.saturating_add(Weight::from_parts(17_564, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:1 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:1 w:0)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn clear_avatar(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `600 + n * (1 ±0)`
+		//  Estimated: `4326`
+		// Minimum execution time: 37_465_000 picoseconds.
+		Weight::from_parts(60_895_556, 4326)
+			// Standard Error: 5_303
+			.saturating_add(Weight::from_parts(45_270, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::ExpiryCursor` (r:1 w:0)
+	/// Proof: `TemplateModule::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::StaleBookmarks` (r:1 w:0)
+	/// Proof: `TemplateModule::StaleBookmarks` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AvatarCursor` (r:1 w:1)
+	/// Proof: `TemplateModule::AvatarCursor` (`max_values`: Some(1), `max_size`: Some(130), added: 625, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:51 w:50)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:50 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 50]`.
+	fn remove_stale_avatars(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `396 + a * (361 ±0)`
+		//  Estimated: `4326 + a * (2773 ±0)`
+		// Minimum execution time: 12_756_000 picoseconds.
+		Weight::from_parts(71_634_933, 4326)
+			// Standard Error: 310_648
+			.saturating_add(Weight::from_parts(26_749_046, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2773).saturating_mul(a.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:1 w:1)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:1 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn add_device(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `654 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 39_058_000 picoseconds.
+		Weight::from_parts(67_473_119, 3775)
+			// Standard Error: 4_595
+			.saturating_add(Weight::from_parts(50_334, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:1 w:1)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:1 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn remove_device(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `704 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 39_361_000 picoseconds.
+		Weight::from_parts(55_857_788, 3775)
+			// Standard Error: 3_939
+			.saturating_add(Weight::from_parts(89_132, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:10 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:0 w:1)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `f` is `[1, 9]`.
+	fn set_recovery(n: u32, _p: u32, f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214 + f * (338 ±0) + n * (2 ±0)`
+		//  Estimated: `3775 + f * (2773 ±0)`
+		// Minimum execution time: 77_159_000 picoseconds.
+		Weight::from_parts(60_564_383, 3775)
+			// Standard Error: 7_758
+			.saturating_add(Weight::from_parts(111_909, 0).saturating_mul(n.into()))
+			// Standard Error: 229_460
+			.saturating_add(Weight::from_parts(13_397_064, 0).saturating_mul(f.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2773).saturating_mul(f.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn remove_recovery(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `593 + n * (3 ±0)`
+		//  Estimated: `4028`
+		// Minimum execution time: 46_624_000 picoseconds.
+		Weight::from_parts(54_174_103, 4028)
+			// Standard Error: 1_326
+			.saturating_add(Weight::from_parts(89_633, 0).saturating_mul(n.into()))
+			// Standard Error: 1_326
+			.saturating_add(Weight::from_parts(6_303, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::ActiveRecoveries` (`max_values`: None, `max_size`: Some(615), added: 3090, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn initiate_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `342 + n * (2 ±0)`
+		//  Estimated: `4080`
+		// Minimum execution time: 107_390_000 picoseconds.
+		Weight::from_parts(128_743_016, 4080)
+			// Standard Error: 3_644
+			.saturating_add(Weight::from_parts(71_739, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TemplateModule::Recoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:0)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::ActiveRecoveries` (`max_values`: None, `max_size`: Some(615), added: 3090, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn vouch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `970 + n * (2 ±0)`
+		//  Estimated: `4080`
+		// Minimum execution time: 46_724_000 picoseconds.
+		Weight::from_parts(54_007_599, 4080)
+			// Standard Error: 1_732
+			.saturating_add(Weight::from_parts(66_947, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::Recoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::ActiveRecoveries` (`max_values`: None, `max_size`: Some(615), added: 3090, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:2)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:2 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:1)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:0)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:1000 w:0)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:1001 w:0)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PollsEndingAt` (r:1000 w:1000)
+	/// Proof: `TemplateModule::PollsEndingAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:1000 w:0)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1000 w:1000)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1000 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:0)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VoterIds` (r:1 w:0)
+	/// Proof: `TemplateModule::VoterIds` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:10 w:10)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:20 w:20)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:10 w:10)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:0 w:1)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:0 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:0 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:0 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn claim_recovery(_n: u32, _p: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (1005 ±0) + t * (998 ±0)`
+		//  Estimated: `130370 + t * (8328 ±0)`
+		// Minimum execution time: 750_071_000 picoseconds.
+		Weight::from_parts(10_442_956_146, 130370)
+			// Standard Error: 2_202_758
+			.saturating_add(Weight::from_parts(119_442_435, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(55_u64))
+			.saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(53_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 8328).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `TemplateModule::ActiveRecoveries` (`max_values`: None, `max_size`: Some(615), added: 3090, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn cancel_recovery(n: u32, _p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `545 + n * (3 ±0)`
+		//  Estimated: `4080`
+		// Minimum execution time: 67_927_000 picoseconds.
+		Weight::from_parts(108_090_191, 4080)
+			// Standard Error: 8_598
+			.saturating_add(Weight::from_parts(99_229, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:1)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:2)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:2 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:0)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:1000 w:0)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:1001 w:0)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PollsEndingAt` (r:1000 w:1000)
+	/// Proof: `TemplateModule::PollsEndingAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:1000 w:0)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1000 w:1000)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1000 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:0)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VoterIds` (r:1 w:0)
+	/// Proof: `TemplateModule::VoterIds` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:10 w:10)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:20 w:20)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:10 w:10)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Judgements` (r:0 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:0 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:0 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:0 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:0 w:1)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn transfer_name(n: u32, _p: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (1004 ±0) + t * (998 ±0)`
+		//  Estimated: `130370 + t * (8328 ±0)`
+		// Minimum execution time: 479_708_000 picoseconds.
+		Weight::from_parts(487_163_000, 130370)
+			// Standard Error: 5_505_418
+			.saturating_add(Weight::from_parts(53_741_383, 0).saturating_mul(n.into()))
+			// Standard Error: 1_408_047
+			.saturating_add(Weight::from_parts(130_867_178, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(52_u64))
+			.saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(50_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 8328).saturating_mul(t.into()))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:1 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:0 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn list_name_for_sale(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262 + n * (2 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 25_331_000 picoseconds.
+		Weight::from_parts(24_016_753, 3775)
+			// Standard Error: 790
+			.saturating_add(Weight::from_parts(43_838, 0).saturating_mul(n.into()))
+			// Standard Error: 790
+			.saturating_add(Weight::from_parts(11_184, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:0)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:1 w:0)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::NamesForSale` (r:1 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	fn unlist_name(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `309 + n * (3 ±0)`
+		//  Estimated: `3775`
+		// Minimum execution time: 26_938_000 picoseconds.
+		Weight::from_parts(26_535_656, 3775)
+			// Standard Error: 2_668
+			.saturating_add(Weight::from_parts(62_758, 0).saturating_mul(n.into()))
+			// Standard Error: 2_668
+			.saturating_add(Weight::from_parts(4_052, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TemplateModule::NamesForSale` (r:1 w:1)
+	/// Proof: `TemplateModule::NamesForSale` (`max_values`: None, `max_size`: Some(282), added: 2757, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AccountByName` (r:1 w:1)
+	/// Proof: `TemplateModule::AccountByName` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Names` (r:1 w:2)
+	/// Proof: `TemplateModule::Names` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Suspended` (r:2 w:0)
+	/// Proof: `TemplateModule::Suspended` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VouchedRecoveries` (r:1 w:0)
+	/// Proof: `TemplateModule::VouchedRecoveries` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::TweetCount` (r:1 w:0)
+	/// Proof: `TemplateModule::TweetCount` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::Tweets` (`max_values`: None, `max_size`: Some(831), added: 3306, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Reports` (r:1000 w:0)
+	/// Proof: `TemplateModule::Reports` (`max_values`: None, `max_size`: Some(5853), added: 8328, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::HiddenTweets` (r:1000 w:0)
+	/// Proof: `TemplateModule::HiddenTweets` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Appeals` (r:1001 w:0)
+	/// Proof: `TemplateModule::Appeals` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Polls` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Polls` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PollsEndingAt` (r:1000 w:1000)
+	/// Proof: `TemplateModule::PollsEndingAt` (`max_values`: None, `max_size`: Some(3614), added: 6089, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Tips` (r:1000 w:2000)
+	/// Proof: `TemplateModule::Tips` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::AssetTips` (r:1000 w:0)
+	/// Proof: `TemplateModule::AssetTips` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ExpiryDeposits` (r:1000 w:1000)
+	/// Proof: `TemplateModule::ExpiryDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::BookmarkedBy` (r:1000 w:0)
+	/// Proof: `TemplateModule::BookmarkedBy` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PinnedTweets` (r:1 w:0)
+	/// Proof: `TemplateModule::PinnedTweets` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::VoterIds` (r:1 w:0)
+	/// Proof: `TemplateModule::VoterIds` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Devices` (r:10 w:10)
+	/// Proof: `TemplateModule::Devices` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::ScheduledTweets` (r:1 w:1)
+	/// Proof: `TemplateModule::ScheduledTweets` (`max_values`: None, `max_size`: Some(5841), added: 8316, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:20 w:20)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:10 w:10)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(10463), added: 12938, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::PasswordHash` (r:0 w:1)
+	/// Proof: `TemplateModule::PasswordHash` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Judgements` (r:0 w:1)
+	/// Proof: `TemplateModule::Judgements` (`max_values`: None, `max_size`: Some(927), added: 3402, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Avatars` (r:0 w:1)
+	/// Proof: `TemplateModule::Avatars` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::DeviceCount` (r:0 w:1)
+	/// Proof: `TemplateModule::DeviceCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TemplateModule::Recoveries` (r:0 w:1)
+	/// Proof: `TemplateModule::Recoveries` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `p` is `[1, 256]`.
+	/// The range of component `t` is `[0, 1000]`.
+	fn buy_name(_n: u32, _p: u32, t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (1004 ±0) + t * (998 ±0)`
+		//  Estimated: `130370 + t * (8328 ±0)`
+		// Minimum execution time: 511_441_000 picoseconds.
+		Weight::from_parts(16_828_834_714, 130370)
+			// Standard Error: 1_654_184
+			.saturating_add(Weight::from_parts(125_949_233, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(53_u64))
+			.saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(52_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 8328).saturating_mul(t.into()))
+	}
 }
@@ -24,7 +24,7 @@
 // For more information, please refer to <http://unlicense.org>
 
 // External crates imports
-use alloc::{vec, vec::Vec};
+use alloc::vec::Vec;
 use frame_support::{
 	genesis_builder_helper::{build_state, get_preset},
 	weights::Weight,
@@ -42,8 +42,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	genesis_config_presets, AccountId, AssetId, Assets, Aura, Balance, Block, BlockNumber,
+	Executive, Grandpa, InherentDataExt, NftCollectionId, NftId, Nonce, Runtime, RuntimeCall,
+	RuntimeGenesisConfig, SessionKeys, System, TemplateModule, TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -214,6 +215,69 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_template_runtime_api::SocialApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		NftCollectionId,
+		NftId,
+	> for Runtime {
+		fn profile(
+			name: Vec<u8>,
+		) -> Option<pallet_template_runtime_api::Profile<AccountId, NftCollectionId, NftId>> {
+			TemplateModule::profile(&name)
+		}
+
+		fn muted_names(name: Vec<u8>) -> Vec<Vec<u8>> {
+			TemplateModule::muted_names(&name)
+		}
+
+		fn messages(
+			name: Vec<u8>,
+			with: Vec<u8>,
+			start: u32,
+			limit: u32,
+		) -> Vec<(u32, pallet_template_runtime_api::Message<AccountId, Balance, BlockNumber>)> {
+			TemplateModule::messages(&name, &with, start, limit)
+		}
+
+		fn poll(author: Vec<u8>, tweet_id: u32) -> Option<pallet_template_runtime_api::Poll<BlockNumber>> {
+			TemplateModule::poll(&author, tweet_id)
+		}
+
+		fn tips(author: Vec<u8>, tweet_id: u32) -> Balance {
+			TemplateModule::tips(&author, tweet_id)
+		}
+
+		fn pinned_tweets(name: Vec<u8>) -> Vec<(u32, pallet_template_runtime_api::Tweet<BlockNumber>)> {
+			TemplateModule::pinned_tweets(&name)
+		}
+
+		fn bookmarks(who: AccountId) -> Vec<(u32, pallet_template_runtime_api::Tweet<BlockNumber>)> {
+			TemplateModule::bookmarks(&who)
+		}
+
+		fn list_tweets(
+			owner: Vec<u8>,
+			list: Vec<u8>,
+			viewer: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(u32, pallet_template_runtime_api::Tweet<BlockNumber>)> {
+			TemplateModule::list_tweets(&owner, &list, viewer, limit)
+		}
+	}
+
+	impl pallet_template_runtime_api::AssetsApi<Block, AccountId, AssetId, Balance> for Runtime {
+		fn account_balances(who: AccountId) -> Vec<(AssetId, Balance)> {
+			Assets::account_balances(who)
+		}
+
+		fn asset_tips(author: Vec<u8>, tweet_id: u32) -> Vec<(AssetId, Balance)> {
+			TemplateModule::asset_tips(&author, tweet_id)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -243,7 +307,9 @@ impl_runtime_apis! {
 			use baseline::Pallet as BaselineBench;
 			use super::*;
 
+			#[allow(non_local_definitions)]
 			impl frame_system_benchmarking::Config for Runtime {}
+			#[allow(non_local_definitions)]
 			impl baseline::Config for Runtime {}
 
 			use frame_support::traits::WhitelistedStorageKeys;
@@ -285,11 +351,11 @@ impl_runtime_apis! {
 		}
 
 		fn get_preset(id: &Option<sp_genesis_builder::PresetId>) -> Option<Vec<u8>> {
-			get_preset::<RuntimeGenesisConfig>(id, |_| None)
+			get_preset::<RuntimeGenesisConfig>(id, genesis_config_presets::get_preset)
 		}
 
 		fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
-			vec![]
+			genesis_config_presets::preset_names()
 		}
 	}
 }
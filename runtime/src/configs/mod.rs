@@ -26,23 +26,36 @@
 // Substrate and Polkadot dependencies
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
+	traits::{
+		fungible::HoldConsideration, AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32,
+		ConstU64, ConstU8, EitherOfDiverse, EqualPrivilegeOnly, InstanceFilter,
+		LinearStoragePrice, VariantCountOf,
+	},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		IdentityFee, Weight,
 	},
 };
-use frame_system::limits::{BlockLength, BlockWeights};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_system::{
+	limits::{BlockLength, BlockWeights},
+	EnsureRoot, EnsureSigned,
+};
+use pallet_asset_tx_payment::FungiblesAdapter;
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+	traits::{BlakeTwo256, ConvertInto, One, Verify},
+	Perbill, RuntimeDebug,
+};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+	AccountId, AssetId, Assets, Aura, Balance, Balances, Block, BlockNumber, Hash, NftCollectionId,
+	NftId, Nfts, Nonce, OriginCaller, PalletInfo, Preimage, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Scheduler, Signature,
+	System, DAYS, EXISTENTIAL_DEPOSIT, HOURS, SLOT_DURATION, UNIT, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -133,7 +146,7 @@ impl pallet_balances::Config for Runtime {
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
 	type RuntimeHoldReason = RuntimeHoldReason;
-	type RuntimeFreezeReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
 }
 
 parameter_types! {
@@ -149,14 +162,473 @@ impl pallet_transaction_payment::Config for Runtime {
 	type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
 }
 
+parameter_types! {
+	pub const AssetDeposit: Balance = 100 * UNIT;
+	pub const AssetAccountDeposit: Balance = UNIT / 100;
+	pub const AssetMetadataDepositBase: Balance = 10 * UNIT;
+	pub const AssetMetadataDepositPerByte: Balance = UNIT / 100;
+	pub const AssetApprovalDeposit: Balance = UNIT / 100;
+}
+
+/// Community tokens. Anyone can create an asset against `AssetDeposit`; only root can create
+/// sufficient assets, which are the ones fees can be paid in.
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<1_000>;
+	type AssetId = AssetId;
+	type AssetIdParameter = codec::Compact<AssetId>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = AssetMetadataDepositBase;
+	type MetadataDepositPerByte = AssetMetadataDepositPerByte;
+	type ApprovalDeposit = AssetApprovalDeposit;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+/// Lets `ChargeAssetTxPayment` take fees in sufficient assets, converted from the native fee by
+/// the ratio of the asset's minimum balance to the existential deposit. Like native fees, asset
+/// fees are burned.
+impl pallet_asset_tx_payment::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Fungibles = Assets;
+	type OnChargeAssetTransaction =
+		FungiblesAdapter<pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>, ()>;
+}
+
+parameter_types! {
+	pub const CollectionDeposit: Balance = 100 * UNIT;
+	pub const ItemDeposit: Balance = UNIT;
+	pub const NftMetadataDepositBase: Balance = 10 * UNIT;
+	pub const NftAttributeDepositBase: Balance = 10 * UNIT;
+	pub const NftDepositPerByte: Balance = UNIT / 100;
+	pub NftFeatures: pallet_nfts::PalletFeatures = pallet_nfts::PalletFeatures::all_enabled();
+}
+
+/// NFTs users can show as their avatar in `pallet_template` profiles. Anyone can create a
+/// collection against `CollectionDeposit`.
+impl pallet_nfts::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = NftCollectionId;
+	type ItemId = NftId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Locker = ();
+	type CollectionDeposit = CollectionDeposit;
+	type ItemDeposit = ItemDeposit;
+	type MetadataDepositBase = NftMetadataDepositBase;
+	type AttributeDepositBase = NftAttributeDepositBase;
+	type DepositPerByte = NftDepositPerByte;
+	type StringLimit = ConstU32<256>;
+	type KeyLimit = ConstU32<64>;
+	type ValueLimit = ConstU32<256>;
+	type ApprovalsLimit = ConstU32<20>;
+	type ItemAttributesApprovalsLimit = ConstU32<20>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = ConstU32<{ 365 * DAYS }>;
+	type MaxAttributesPerCall = ConstU32<10>;
+	type Features = NftFeatures;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_utility::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MultisigDepositBase: Balance = UNIT;
+	pub const MultisigDepositFactor: Balance = UNIT / 100;
+}
+
+/// Multisig accounts, for example to control a brand's name together.
+impl pallet_multisig::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type DepositBase = MultisigDepositBase;
+	type DepositFactor = MultisigDepositFactor;
+	type MaxSignatories = ConstU32<20>;
+	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
+}
+
+/// What a proxy may do on behalf of the account that added it.
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+	Default,
+)]
+pub enum ProxyType {
+	/// Any call.
+	#[default]
+	Any,
+	/// Posting, deleting and pinning tweets and batches of those calls, for example a social
+	/// media manager posting under a brand's name without holding its key. Calls that move or
+	/// hold funds, like tips and expiring tweets, are left out. Calls made through a proxy pay
+	/// the usual fee, as only the brand's own calls count as feeless posts.
+	Social,
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, call: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::Social => match call {
+				RuntimeCall::TemplateModule(call) => matches!(
+					call,
+					pallet_template::Call::create_tweet { .. } |
+						pallet_template::Call::create_poll_tweet { .. } |
+						pallet_template::Call::schedule_tweet { .. } |
+						pallet_template::Call::cancel_scheduled_tweet { .. } |
+						pallet_template::Call::delete_tweet { .. } |
+						pallet_template::Call::pin_tweet { .. } |
+						pallet_template::Call::unpin_tweet { .. }
+				),
+				RuntimeCall::Utility(
+					pallet_utility::Call::batch { calls } |
+					pallet_utility::Call::batch_all { calls } |
+					pallet_utility::Call::force_batch { calls },
+				) => calls.iter().all(|call| self.filter(call)),
+				_ => false,
+			},
+		}
+	}
+
+	fn is_superset(&self, other: &Self) -> bool {
+		self == other || *self == ProxyType::Any
+	}
+}
+
+parameter_types! {
+	pub const ProxyDepositBase: Balance = UNIT;
+	pub const ProxyDepositFactor: Balance = UNIT / 100;
+	pub const AnnouncementDepositBase: Balance = UNIT;
+	pub const AnnouncementDepositFactor: Balance = UNIT / 50;
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = ConstU32<32>;
+	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+	type MaxPending = ConstU32<32>;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+}
+
+parameter_types! {
+	pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+	pub MaxProposalWeight: Weight = Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block;
+}
+
+/// The council moderates the template pallet's content, see `pallet_template::ModeratorOrigin`.
+pub type CouncilCollective = pallet_collective::Instance1;
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = CouncilMotionDuration;
+	type MaxProposals = ConstU32<100>;
+	type MaxMembers = ConstU32<100>;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+	type SetMembersOrigin = EnsureRoot<AccountId>;
+	type MaxProposalWeight = MaxProposalWeight;
+}
+
+/// Root, or at least half of the council.
+type ModeratorOrigin = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+>;
+
+parameter_types! {
+	pub const BasicDeposit: Balance = 10 * UNIT;
+	pub const ByteDeposit: Balance = UNIT / 100;
+	pub const SubAccountDeposit: Balance = 2 * UNIT;
+}
+
+impl pallet_identity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BasicDeposit = BasicDeposit;
+	type ByteDeposit = ByteDeposit;
+	type SubAccountDeposit = SubAccountDeposit;
+	type MaxSubAccounts = ConstU32<100>;
+	type IdentityInformation = pallet_identity::legacy::IdentityInfo<ConstU32<100>>;
+	type MaxRegistrars = ConstU32<20>;
+	type Slashed = ();
+	type ForceOrigin = EnsureRoot<AccountId>;
+	/// Sudo curates the registrar set.
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	type UsernameAuthorityOrigin = EnsureRoot<AccountId>;
+	type PendingUsernameExpiration = ConstU32<{ 7 * DAYS }>;
+	type MaxSuffixLength = ConstU32<7>;
+	type MaxUsernameLength = ConstU32<32>;
+	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
+}
+
+/// Shows `pallet_identity` judgements in `pallet_template` profiles.
+///
+/// An account's identity counts as `Erroneous` as soon as one registrar says so, and otherwise
+/// takes the best judgement any registrar gave it.
+pub struct IdentityJudgements;
+
+impl pallet_template::IdentityJudgement<AccountId> for IdentityJudgements {
+	fn judgement(who: &AccountId) -> Option<pallet_template::Judgement> {
+		use pallet_identity::Judgement as Identity;
+		use pallet_template::Judgement;
+
+		let (registration, _) = pallet_identity::IdentityOf::<Runtime>::get(who)?;
+		let judgements = || registration.judgements.iter().map(|(_, judgement)| judgement);
+		if judgements().any(|judgement| *judgement == Identity::Erroneous) {
+			Some(Judgement::Erroneous)
+		} else if judgements().any(|judgement| *judgement == Identity::KnownGood) {
+			Some(Judgement::KnownGood)
+		} else if judgements().any(|judgement| *judgement == Identity::Reasonable) {
+			Some(Judgement::Reasonable)
+		} else {
+			None
+		}
+	}
+}
+
+/// Mints the NFTs `pallet_template` benchmarks set as avatars, in a collection of their own that
+/// takes no deposits.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NftAvatarsBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_template::BenchmarkHelper<AccountId, NftCollectionId, NftId>
+	for NftAvatarsBenchmarkHelper
+{
+	fn mint_nft(owner: &AccountId) -> (NftCollectionId, NftId) {
+		use frame_support::traits::tokens::nonfungibles_v2::Mutate;
+		use pallet_nfts::{CollectionConfig, CollectionSetting, CollectionSettings};
+
+		let collection = pallet_nfts::NextCollectionId::<Runtime>::get().unwrap_or_default();
+		let config = CollectionConfig {
+			settings: CollectionSettings::from_disabled(CollectionSetting::DepositRequired.into()),
+			..Default::default()
+		};
+		Nfts::force_create(RuntimeOrigin::root(), owner.clone().into(), config)
+			.expect("root can create collections");
+		Nfts::mint_into(&collection, &0, owner, &Default::default(), true)
+			.expect("the collection owner can mint");
+		(collection, 0)
+	}
+}
+
+parameter_types! {
+	pub const PreimageBaseDeposit: Balance = UNIT;
+	pub const PreimageByteDeposit: Balance = UNIT / 100;
+	pub const PreimageHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type Consideration = HoldConsideration<
+		AccountId,
+		Balances,
+		PreimageHoldReason,
+		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+	>;
+}
+
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight =
+		Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type MaxScheduledPerBlock = ConstU32<50>;
+	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type Preimages = Preimage;
+}
+
+parameter_types! {
+	pub const MessageDeposit: Balance = UNIT / 10;
+	pub const RecoveryDeposit: Balance = 10 * UNIT;
+	pub const ExpiringTweetDeposit: Balance = UNIT / 10;
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type Assets = Assets;
+	type MaxTipAssets = ConstU32<16>;
+	type NftCollectionId = NftCollectionId;
+	type NftId = NftId;
+	type Nfts = Nfts;
+	type MaxAvatarChecksPerBlock = ConstU32<50>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = NftAvatarsBenchmarkHelper;
+	type MessageDeposit = MessageDeposit;
+	type MaxMessagesPerConversation = ConstU32<1_000>;
+	type MaxPollsEndingPerBlock = ConstU32<100>;
+	type MaxPollDuration = ConstU32<{ 7 * DAYS }>;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type Scheduler = Scheduler;
+	type MaxScheduledTweets = ConstU32<20>;
+	type MaxScheduledTweetsPerBlock = ConstU32<2>;
+	type MaxExpiringPerBlock = ConstU32<100>;
+	type ExpiringTweetDeposit = ExpiringTweetDeposit;
+	type MaxPinnedTweets = ConstU32<3>;
+	type MaxBookmarks = ConstU32<500>;
+	type MaxListsPerUser = ConstU32<20>;
+	type MaxListMembers = ConstU32<100>;
+	type MaxDevices = ConstU32<10>;
+	type MaxFriends = ConstU32<9>;
+	type RecoveryDeposit = RecoveryDeposit;
+	type MaxTweetsPerUser = ConstU32<1_000>;
+	type MaxPostsPerPeriod = ConstU32<50>;
+	type QuotaPeriod = ConstU32<HOURS>;
+	type PostLongevity = ConstU64<{ HOURS as u64 }>;
+	type MaxMentions = ConstU32<10>;
+	type ModeratorOrigin = ModeratorOrigin;
+	type MaxReportsPerTweet = ConstU32<20>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type MaxRegistrars = ConstU32<20>;
+	type Identity = IdentityJudgements;
+	type RequireIdentity = ConstBool<false>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::BoundedVec;
+
+	fn text<const N: u32>(text: &str) -> BoundedVec<u8, ConstU32<N>> {
+		BoundedVec::truncate_from(text.as_bytes().to_vec())
+	}
+
+	fn batch(calls: Vec<RuntimeCall>) -> RuntimeCall {
+		RuntimeCall::Utility(pallet_utility::Call::batch_all { calls })
+	}
+
+	#[test]
+	fn social_proxies_post_but_never_move_funds() {
+		let post = RuntimeCall::TemplateModule(pallet_template::Call::create_tweet {
+			name: text("brand"),
+			password: text("secret"),
+			content: text("gm"),
+		});
+		let pin = RuntimeCall::TemplateModule(pallet_template::Call::pin_tweet {
+			name: text("brand"),
+			password: text("secret"),
+			tweet_id: 0,
+		});
+		let tip = RuntimeCall::TemplateModule(pallet_template::Call::tip_tweet {
+			name: text("creator"),
+			tweet_id: 0,
+			amount: UNIT,
+		});
+		let tip_with_asset =
+			RuntimeCall::TemplateModule(pallet_template::Call::tip_tweet_with_asset {
+				name: text("creator"),
+				tweet_id: 0,
+				asset_id: 1,
+				amount: 1,
+			});
+		let expiring = RuntimeCall::TemplateModule(pallet_template::Call::create_expiring_tweet {
+			name: text("brand"),
+			password: text("secret"),
+			content: text("gm"),
+			expires_at: 10,
+		});
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+
+		let allowed = [post.clone(), pin.clone(), batch(vec![post.clone(), pin])];
+		assert!(allowed.iter().all(|call| ProxyType::Social.filter(call)));
+
+		let mut denied = vec![tip.clone(), tip_with_asset.clone(), expiring.clone(), remark];
+		// Nested batches are checked all the way down
+		let nested = |call| batch(vec![post.clone(), batch(vec![call])]);
+		denied.extend([tip, tip_with_asset, expiring].map(nested));
+		assert!(denied.iter().all(|call| !ProxyType::Social.filter(call)));
+		assert!(denied.iter().all(|call| ProxyType::Any.filter(call)));
+	}
+
+	#[test]
+	fn tweet_walking_calls_fit_in_a_block() {
+		use frame_support::{
+			dispatch::{DispatchClass, GetDispatchInfo},
+			traits::GetCallName,
+		};
+		use pallet_template::Call;
+
+		let max_extrinsic = RuntimeBlockWeights::get()
+			.get(DispatchClass::Normal)
+			.max_extrinsic
+			.expect("normal extrinsics have a weight limit");
+		// The longest names and passwords, the weights assume `MaxTweetsPerUser` tweets
+		let name = || text(&"n".repeat(256));
+		let password = || text(&"p".repeat(256));
+		let calls = [
+			Call::get_user_tweets { name: name() },
+			Call::update_user_name { name: name(), password: password(), new_name: name() },
+			Call::delete_user { name: name(), password: password() },
+			Call::claim_recovery { name: name(), new_password: password() },
+			Call::transfer_name { name: name(), password: password(), to: AccountId::new([0; 32]) },
+			Call::buy_name { name: name(), price: UNIT, new_password: password() },
+		];
+		for call in calls {
+			let weight = RuntimeCall::TemplateModule(call.clone()).get_dispatch_info().weight;
+			assert!(weight.all_lte(max_extrinsic), "{} weighs {weight:?}", call.get_call_name());
+		}
+	}
 }
@@ -0,0 +1,151 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+use crate::AccountId;
+use alloc::{vec, vec::Vec};
+use serde_json::Value;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_genesis_builder::{self, PresetId};
+use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
+
+/// Preset with the development authorities plus a handful of registered users and tweets.
+pub const SOCIAL_DEMO_RUNTIME_PRESET: &str = "social-demo";
+
+// Returns the genesis config presets populated with given parameters.
+fn testnet_genesis(
+	initial_authorities: Vec<(AuraId, GrandpaId)>,
+	endowed_accounts: Vec<AccountId>,
+	root: AccountId,
+) -> Value {
+	serde_json::json!({
+		"balances": {
+			// Configure endowed accounts with initial balance of 1 << 60.
+			"balances": endowed_accounts.iter().cloned().map(|k| (k, 1u64 << 60)).collect::<Vec<_>>(),
+		},
+		"aura": {
+			"authorities": initial_authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>(),
+		},
+		"grandpa": {
+			"authorities": initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
+		},
+		"sudo": {
+			// Assign network admin rights.
+			"key": Some(root.clone()),
+		},
+		"council": {
+			// Moderate the template pallet's content.
+			"members": vec![root],
+		},
+	})
+}
+
+/// Return the development genesis config.
+pub fn development_config_genesis() -> Value {
+	testnet_genesis(
+		vec![(Sr25519Keyring::Alice.public().into(), Ed25519Keyring::Alice.public().into())],
+		vec![
+			Sr25519Keyring::Alice.to_account_id(),
+			Sr25519Keyring::Bob.to_account_id(),
+			Sr25519Keyring::AliceStash.to_account_id(),
+			Sr25519Keyring::BobStash.to_account_id(),
+		],
+		Sr25519Keyring::Alice.to_account_id(),
+	)
+}
+
+/// Return the local genesis config preset.
+pub fn local_config_genesis() -> Value {
+	testnet_genesis(
+		vec![
+			(Sr25519Keyring::Alice.public().into(), Ed25519Keyring::Alice.public().into()),
+			(Sr25519Keyring::Bob.public().into(), Ed25519Keyring::Bob.public().into()),
+		],
+		Sr25519Keyring::iter()
+			.filter(|v| v != &Sr25519Keyring::One && v != &Sr25519Keyring::Two)
+			.map(|v| v.to_account_id())
+			.collect::<Vec<_>>(),
+		Sr25519Keyring::Alice.to_account_id(),
+	)
+}
+
+/// Return the social demo genesis config: the development setup with Alice, Bob and Charlie
+/// already registered in the template pallet and a few tweets to browse.
+pub fn social_demo_config_genesis() -> Value {
+	let mut genesis = testnet_genesis(
+		vec![(Sr25519Keyring::Alice.public().into(), Ed25519Keyring::Alice.public().into())],
+		vec![
+			Sr25519Keyring::Alice.to_account_id(),
+			Sr25519Keyring::Bob.to_account_id(),
+			Sr25519Keyring::Charlie.to_account_id(),
+		],
+		Sr25519Keyring::Alice.to_account_id(),
+	);
+
+	// Every demo user has the password "password".
+	let user = |who: Sr25519Keyring, name: &str, birthday: (u16, u8, u8)| {
+		(who.to_account_id(), name.as_bytes().to_vec(), b"password".to_vec(), birthday)
+	};
+	let tweet = |name: &str, content: &str| (name.as_bytes().to_vec(), content.as_bytes().to_vec());
+
+	genesis["templateModule"] = serde_json::json!({
+		"users": vec![
+			user(Sr25519Keyring::Alice, "alice", (1990, 5, 4)),
+			user(Sr25519Keyring::Bob, "bob", (1985, 11, 23)),
+			user(Sr25519Keyring::Charlie, "charlie", (2001, 2, 14)),
+		],
+		"tweets": vec![
+			tweet("alice", "Hello from genesis!"),
+			tweet("alice", "Building a social network on Substrate."),
+			tweet("bob", "First!"),
+			tweet("charlie", "Is this thing on?"),
+		],
+	});
+	genesis
+}
+
+/// Provides the JSON representation of predefined genesis config for given `id`.
+pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
+	let patch = match id.try_into() {
+		Ok(sp_genesis_builder::DEV_RUNTIME_PRESET) => development_config_genesis(),
+		Ok(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET) => local_config_genesis(),
+		Ok(SOCIAL_DEMO_RUNTIME_PRESET) => social_demo_config_genesis(),
+		_ => return None,
+	};
+	Some(
+		serde_json::to_string(&patch)
+			.expect("serialization to json is expected to work. qed.")
+			.into_bytes(),
+	)
+}
+
+/// List of supported presets.
+pub fn preset_names() -> Vec<PresetId> {
+	vec![
+		PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
+		PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
+		PresetId::from(SOCIAL_DEMO_RUNTIME_PRESET),
+	]
+}